@@ -4,9 +4,9 @@ use crate::avm1::function::{Executable, FunctionObject};
 use crate::avm1::object::date_object::DateObject;
 use crate::avm1::property_decl::{define_properties_on, Declaration};
 use crate::avm1::{Object, TObject, Value};
-use crate::locale::{get_current_date_time, get_timezone};
+use crate::locale::{get_current_date_time, get_timezone, local_to_utc};
 use crate::string::AvmString;
-use chrono::{DateTime, Datelike, Duration, LocalResult, TimeZone, Timelike, Utc};
+use chrono::{DateTime, Datelike, Duration, LocalResult, NaiveDate, TimeZone, Timelike, Utc};
 use gc_arena::{Collect, MutationContext};
 use num_traits::ToPrimitive;
 
@@ -147,6 +147,15 @@ struct DateAdjustment<
     minute: Option<Option<f64>>,
     second: Option<Option<f64>>,
     millisecond: Option<Option<f64>>,
+
+    /// Set by the `*_opt` setters when an argument is missing or `undefined`.
+    ///
+    /// AVM1 stops reading arguments at the first `undefined` one, so that field and every
+    /// field after it keep their current value, even if a later argument was given.
+    /// e.g. `setUTCHours(5, undefined, 30)` only changes the hour.
+    ///
+    /// This is unlike AVM2, where an explicit `undefined` coerces to `NaN` and
+    /// invalidates the date.
     ignore_next: bool,
 }
 
@@ -550,20 +559,27 @@ impl<'builder, 'activation_a, 'gc, 'gc_context, T: TimeZone>
             let millisecond =
                 self.check_value(self.millisecond, current.timestamp_subsec_millis())?;
 
-            let duration = Duration::days(day - 1)
-                + Duration::hours(hour)
-                + Duration::minutes(minute)
-                + Duration::seconds(second)
-                + Duration::milliseconds(millisecond);
-
-            if let LocalResult::Single(Some(result)) = current
-                .timezone()
-                .ymd_opt(year, (month + 1) as u32, 1)
-                .and_hms_opt(0, 0, 0)
-                .map(|date| date.checked_add_signed(duration))
-            {
-                return Some(result.with_timezone(&Utc));
-            }
+            // Huge components would overflow (and panic) when building a `Duration` out of
+            // them, so the offset is summed up in milliseconds with checked arithmetic.
+            let duration = [
+                (day, 86_400_000),
+                (hour, 3_600_000),
+                (minute, 60_000),
+                (second, 1_000),
+                (millisecond, 1),
+            ]
+            .into_iter()
+            .try_fold(-86_400_000i64, |total, (value, scale)| {
+                value.checked_mul(scale)?.checked_add(total)
+            })
+            .map(Duration::milliseconds)?;
+
+            // The fields are added up in local time and only then converted into an instant,
+            // so that a DST change between the 1st of the month and the result doesn't shift it.
+            let local = NaiveDate::from_ymd_opt(year, (month + 1) as u32, 1)?
+                .and_hms(0, 0, 0)
+                .checked_add_signed(duration)?;
+            return local_to_utc(self.timezone, &local);
         }
 
         None
@@ -599,7 +615,7 @@ fn constructor<'gc>(
             // We need a starting value to adjust from.
             this.set_date_time(
                 activation.context.gc_context,
-                Some(timezone.ymd(0, 1, 1).and_hms(0, 0, 0).into()),
+                Some(timezone.ymd(0, 1, 1).and_hms(0, 0, 0).with_timezone(&Utc)),
             );
 
             DateAdjustment::new(activation, &timezone)
@@ -669,10 +669,8 @@ fn set_y<'gc>(
     Ok(())
 }
 
-fn x_scale<'gc>(activation: &mut Activation<'_, 'gc, '_>, this: DisplayObject<'gc>) -> Value<'gc> {
-    this.scale_x(activation.context.gc_context)
-        .into_fraction()
-        .into()
+fn x_scale<'gc>(_activation: &mut Activation<'_, 'gc, '_>, this: DisplayObject<'gc>) -> Value<'gc> {
+    this.scale_x().into_fraction().into()
 }
 
 fn set_x_scale<'gc>(
@@ -686,10 +684,8 @@ fn set_x_scale<'gc>(
     Ok(())
 }
 
-fn y_scale<'gc>(activation: &mut Activation<'_, 'gc, '_>, this: DisplayObject<'gc>) -> Value<'gc> {
-    this.scale_y(activation.context.gc_context)
-        .into_fraction()
-        .into()
+fn y_scale<'gc>(_activation: &mut Activation<'_, 'gc, '_>, this: DisplayObject<'gc>) -> Value<'gc> {
+    this.scale_y().into_fraction().into()
 }
 
 fn set_y_scale<'gc>(
@@ -783,8 +779,11 @@ fn set_height<'gc>(
     Ok(())
 }
 
-fn rotation<'gc>(activation: &mut Activation<'_, 'gc, '_>, this: DisplayObject<'gc>) -> Value<'gc> {
-    let degrees: f64 = this.rotation(activation.context.gc_context).into();
+fn rotation<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: DisplayObject<'gc>,
+) -> Value<'gc> {
+    let degrees: f64 = this.rotation().into();
     degrees.into()
 }
 
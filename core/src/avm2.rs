@@ -65,6 +65,21 @@ const BROADCAST_WHITELIST: [&str; 3] = ["enterFrame", "exitFrame", "frameConstru
 /// with a proper Avm2Error enum.
 pub type Error = Box<dyn std::error::Error>;
 
+/// An error thrown by native code along with an AVM2 value, such as an `Error` object.
+///
+/// The value itself can't be kept in an `Error`, so it's stored in the `Avm2` until a
+/// catch block picks it up. This holds the message to report if nothing does.
+#[derive(Debug)]
+pub struct ThrownValue(String);
+
+impl std::fmt::Display for ThrownValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ThrownValue {}
+
 /// The state of an AVM2 interpreter.
 #[derive(Collect)]
 #[collect(no_drop)]
@@ -94,6 +109,9 @@ pub struct Avm2<'gc> {
     /// collector does not support weak references.
     broadcast_list: FnvHashMap<AvmString<'gc>, Vec<Object<'gc>>>,
 
+    /// The value of the last `ThrownValue` error, which hasn't been caught yet.
+    thrown_value: Option<Value<'gc>>,
+
     #[cfg(feature = "avm_debug")]
     pub debug_output: bool,
 }
@@ -110,6 +128,7 @@ impl<'gc> Avm2<'gc> {
             native_method_table: Default::default(),
             native_instance_allocator_table: Default::default(),
             broadcast_list: Default::default(),
+            thrown_value: None,
 
             #[cfg(feature = "avm_debug")]
             debug_output: false,
@@ -122,6 +141,23 @@ impl<'gc> Avm2<'gc> {
         globals::load_player_globals(&mut activation, globals)
     }
 
+    /// Throw `value` from native code, returning the error to return to do so.
+    ///
+    /// Catch blocks receive `value` itself. If it isn't caught, `message` is reported.
+    pub fn throw_value(&mut self, value: Value<'gc>, message: impl Into<String>) -> Error {
+        self.thrown_value = Some(value);
+        Box::new(ThrownValue(message.into()))
+    }
+
+    /// Take the value of a `ThrownValue` error that is being caught.
+    fn take_thrown_value(&mut self, error: &Error) -> Option<Value<'gc>> {
+        if error.is::<ThrownValue>() {
+            self.thrown_value.take()
+        } else {
+            None
+        }
+    }
+
     /// Return the current set of system classes.
     ///
     /// This function panics if the interpreter has not yet been initialized.
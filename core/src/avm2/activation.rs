@@ -813,9 +813,14 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
                     && e.type_name.0 == 0
                 // Currently we support only typeless catch clauses
                 {
-                    // Emulate pushing the exception object
-                    let ws = WString::from_utf8_owned(error.to_string());
-                    let exception = AvmString::new(self.context.gc_context, ws);
+                    // Emulate pushing the exception object, unless native code threw a real one
+                    let exception = match self.context.avm2.take_thrown_value(&error) {
+                        Some(value) => value,
+                        None => {
+                            let ws = WString::from_utf8_owned(error.to_string());
+                            AvmString::new(self.context.gc_context, ws).into()
+                        }
+                    };
                     self.context.avm2.push(exception);
 
                     self.scope_stack.clear();
@@ -1999,7 +2004,8 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         let value2 = self.context.avm2.pop();
         let value1 = self.context.avm2.pop();
 
-        // TODO: Special handling required for `Date` and ECMA-357/E4X `XML`
+        // `Date` is covered by its default hint preferring strings below.
+        // TODO: Special handling required for ECMA-357/E4X `XML`
         let sum_value = match (value1, value2) {
             (Value::Number(n1), Value::Number(n2)) => Value::Number(n1 + n2),
             (Value::String(s), value2) => Value::String(AvmString::concat(
@@ -100,6 +100,9 @@ pub struct SystemClasses<'gc> {
     pub illegaloperationerror: ClassObject<'gc>,
     pub eventdispatcher: ClassObject<'gc>,
     pub rectangle: ClassObject<'gc>,
+    pub point: ClassObject<'gc>,
+    pub perspectiveprojection: ClassObject<'gc>,
+    pub vector3d: ClassObject<'gc>,
 }
 
 impl<'gc> SystemClasses<'gc> {
@@ -169,6 +172,9 @@ impl<'gc> SystemClasses<'gc> {
             illegaloperationerror: object,
             eventdispatcher: object,
             rectangle: object,
+            point: object,
+            perspectiveprojection: object,
+            vector3d: object,
         }
     }
 }
@@ -720,6 +726,9 @@ fn load_playerglobal<'gc>(
             ("flash.geom", "Rectangle", rectangle),
             ("flash.geom", "Transform", transform),
             ("flash.geom", "ColorTransform", colortransform),
+            ("flash.geom", "Point", point),
+            ("flash.geom", "PerspectiveProjection", perspectiveprojection),
+            ("flash.geom", "Vector3D", vector3d),
         ]
     );
 
@@ -0,0 +1,5 @@
+// This is a stub - the actual class is defined in `vector.rs`
+package __AS3__.vec {
+	public final dynamic class Vector {
+	}
+}
@@ -9,9 +9,9 @@ use crate::avm2::Error;
 use crate::avm2::Multiname;
 use crate::avm2::Namespace;
 use crate::avm2::QName;
-use crate::locale::{get_current_date_time, get_timezone};
+use crate::locale::{get_current_date_time, get_timezone, local_to_utc, LocalTimezone};
 use crate::string::{utils as string_utils, AvmString, WStr};
-use chrono::{DateTime, Datelike, Duration, FixedOffset, LocalResult, TimeZone, Timelike, Utc};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, TimeZone, Timelike, Utc};
 use gc_arena::{GcCell, MutationContext};
 use num_traits::ToPrimitive;
 
@@ -58,9 +58,15 @@ const PUBLIC_INSTANCE_AND_PROTO_METHODS: &[(&str, NativeMethodImpl)] = &[
     ("toTimeString", to_time_string),
     ("toLocaleTimeString", to_locale_time_string),
     ("toDateString", to_date_string),
-    ("toLocaleDateString", to_date_string),
+    ("toLocaleDateString", to_locale_date_string),
 ];
 
+/// Builds a new timestamp from some of a date's components.
+///
+/// A component left as `None` was not passed by the caller, so the date's
+/// current value is kept; `setMonth(5)` keeps the day of the month. A component
+/// that was passed but isn't a finite number, including an explicit
+/// `undefined`, makes the whole date invalid.
 struct DateAdjustment<
     'builder,
     'activation_a: 'builder,
@@ -174,52 +180,45 @@ impl<'builder, 'activation_a, 'gc, 'gc_context, T: TimeZone>
         }
     }
 
-    fn check_mapped_value(
-        &self,
-        specified: Option<Option<f64>>,
-        map: impl FnOnce(i64) -> i64,
-        current: impl ToPrimitive,
-    ) -> Option<i64> {
-        match specified {
-            Some(Some(value)) if value.is_finite() => Some(map(value as i64)),
-            Some(_) => None,
-            None => current.to_i64(),
-        }
-    }
-
     fn calculate(&mut self, current: DateTime<T>) -> Option<DateTime<Utc>> {
-        let month_rem = self
-            .month
-            .flatten()
-            .map(|v| v as i64)
-            .unwrap_or_default()
-            .div_euclid(12);
-        let month = self.check_mapped_value(self.month, |v| v.rem_euclid(12), current.month0())?;
+        // Months outside of 0-11 carry over into the year, so that e.g. -1 is December
+        // of the previous year. Both halves come from the same value, and `rem_euclid`
+        // keeps the month in 0-11 even for negative inputs.
+        let month = self.check_value(self.month, current.month0())?;
+        let (month_rem, month) = (month.div_euclid(12), month.rem_euclid(12));
+        // Years that don't even fit in an `i32` are far outside of the range
+        // that a `Date` can represent, so they must not be allowed to wrap around.
         let year = self
             .check_value(self.year, current.year())?
-            .wrapping_add(month_rem) as i32;
+            .checked_add(month_rem)
+            .and_then(|year| i32::try_from(year).ok())?;
         let day = self.check_value(self.day, current.day())?;
         let hour = self.check_value(self.hour, current.hour())?;
         let minute = self.check_value(self.minute, current.minute())?;
         let second = self.check_value(self.second, current.second())?;
         let millisecond = self.check_value(self.millisecond, current.timestamp_subsec_millis())?;
 
-        let duration = Duration::days(day - 1)
-            + Duration::hours(hour)
-            + Duration::minutes(minute)
-            + Duration::seconds(second)
-            + Duration::milliseconds(millisecond);
-
-        if let LocalResult::Single(Some(result)) = current
-            .timezone()
-            .ymd_opt(year, (month + 1) as u32, 1)
-            .and_hms_opt(0, 0, 0)
-            .map(|date| date.checked_add_signed(duration))
-        {
-            Some(result.with_timezone(&Utc))
-        } else {
-            None
-        }
+        // Huge components would overflow (and panic) when building a `Duration` out of
+        // them, so the offset is summed up in milliseconds with checked arithmetic.
+        let duration = [
+            (day, 86_400_000),
+            (hour, 3_600_000),
+            (minute, 60_000),
+            (second, 1_000),
+            (millisecond, 1),
+        ]
+        .into_iter()
+        .try_fold(-86_400_000i64, |total, (value, scale)| {
+            value.checked_mul(scale)?.checked_add(total)
+        })
+        .map(Duration::milliseconds)?;
+
+        // The fields are added up in local time and only then converted into an instant,
+        // so that a DST change between the 1st of the month and the result doesn't shift it.
+        let local = NaiveDate::from_ymd_opt(year, month as u32 + 1, 1)?
+            .and_hms(0, 0, 0)
+            .checked_add_signed(duration)?;
+        local_to_utc(self.timezone, &local)
     }
 
     fn apply(&mut self, object: DateObject<'gc>) -> f64 {
@@ -238,6 +237,26 @@ impl<'builder, 'activation_a, 'gc, 'gc_context, T: TimeZone>
     }
 }
 
+/// The largest number of milliseconds away from the epoch that a date may be.
+const MAX_TIME: f64 = 8.64e15;
+
+/// Convert a timestamp in milliseconds into a date, following ECMA-262's `TimeClip`.
+///
+/// Fractional milliseconds are truncated towards zero (so both `1.9` and `-1.9`
+/// lose their fraction), and timestamps further than `MAX_TIME` from the epoch
+/// produce an invalid date rather than saturating or wrapping.
+///
+/// NOTE: chrono only reaches about 262,000 years from the epoch, while Flash accepts
+/// dates up to 275,760 years out. The timestamps in between are treated as invalid,
+/// so that a date is never valid for `getTime` but invalid for its fields.
+pub fn time_clip(time: f64) -> Option<DateTime<Utc>> {
+    if !time.is_finite() || time.abs() > MAX_TIME {
+        return None;
+    }
+
+    Utc.timestamp_millis_opt(time.trunc() as i64).single()
+}
+
 /// Implements `Date`'s instance constructor.
 pub fn instance_init<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
@@ -255,7 +274,7 @@ pub fn instance_init<'gc>(
                     // We need a starting value to adjust from.
                     date.set_date_time(
                         activation.context.gc_context,
-                        Some(timezone.ymd(0, 1, 1).and_hms(0, 0, 0).into()),
+                        Some(timezone.ymd(0, 1, 1).and_hms(0, 0, 0).with_timezone(&Utc)),
                     );
 
                     DateAdjustment::new(activation, &timezone)
@@ -269,18 +288,9 @@ pub fn instance_init<'gc>(
                         .map_year(|year| if year < 100.0 { year + 1900.0 } else { year })
                         .apply(date);
                 } else {
+                    // Another `Date` coerces through `valueOf`, so this also copies its timestamp.
                     let timestamp = timestamp.coerce_to_number(activation)?;
-                    if timestamp.is_finite() {
-                        if let LocalResult::Single(time) =
-                            Utc.timestamp_millis_opt(timestamp as i64)
-                        {
-                            date.set_date_time(activation.context.gc_context, Some(time))
-                        } else {
-                            date.set_date_time(activation.context.gc_context, None);
-                        }
-                    } else {
-                        date.set_date_time(activation.context.gc_context, None);
-                    }
+                    date.set_date_time(activation.context.gc_context, time_clip(timestamp));
                 }
             } else {
                 date.set_date_time(activation.context.gc_context, Some(get_current_date_time()))
@@ -323,6 +333,9 @@ pub fn class_init<'gc>(
 }
 
 /// Implements `time` property's getter, and the `getTime` method. This will also be used for `valueOf`.
+///
+/// This reads the stored UTC timestamp directly, so unlike the local field getters it never
+/// has to convert into the local timezone.
 pub fn time<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
@@ -336,6 +349,9 @@ pub fn time<'gc>(
 }
 
 /// Implements `time` property's setter, and the `setTime` method.
+///
+/// The returned timestamp is only observable through `setTime`; an assignment
+/// to `time` evaluates to the assigned value, as with any other setter.
 pub fn set_time<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
@@ -346,14 +362,12 @@ pub fn set_time<'gc>(
             .get(0)
             .unwrap_or(&Value::Undefined)
             .coerce_to_number(activation)?;
-        if new_time.is_finite() {
-            let time = Utc.timestamp_millis(new_time as i64);
-            this.set_date_time(activation.context.gc_context, Some(time));
-            return Ok((time.timestamp_millis() as f64).into());
-        } else {
-            this.set_date_time(activation.context.gc_context, None);
-            return Ok(f64::NAN.into());
-        }
+        let time = time_clip(new_time);
+        this.set_date_time(activation.context.gc_context, time);
+        // Return what was actually stored, so that timestamps `time_clip` rejects give `NaN`.
+        return Ok(time
+            .map_or(f64::NAN, |time| time.timestamp_millis() as f64)
+            .into());
     }
 
     Ok(Value::Undefined)
@@ -366,10 +380,7 @@ pub fn milliseconds<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this.and_then(|this| this.as_date_object()) {
-        if let Some(date) = this
-            .date_time()
-            .map(|date| date.with_timezone(&get_timezone()))
-        {
+        if let Some(date) = this.local_date_time() {
             return Ok((date.timestamp_subsec_millis() as f64).into());
         } else {
             return Ok(f64::NAN.into());
@@ -401,10 +412,7 @@ pub fn seconds<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this.and_then(|this| this.as_date_object()) {
-        if let Some(date) = this
-            .date_time()
-            .map(|date| date.with_timezone(&get_timezone()))
-        {
+        if let Some(date) = this.local_date_time() {
             return Ok((date.second() as f64).into());
         } else {
             return Ok(f64::NAN.into());
@@ -437,10 +445,7 @@ pub fn minutes<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this.and_then(|this| this.as_date_object()) {
-        if let Some(date) = this
-            .date_time()
-            .map(|date| date.with_timezone(&get_timezone()))
-        {
+        if let Some(date) = this.local_date_time() {
             return Ok((date.minute() as f64).into());
         } else {
             return Ok(f64::NAN.into());
@@ -474,10 +479,7 @@ pub fn hours<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this.and_then(|this| this.as_date_object()) {
-        if let Some(date) = this
-            .date_time()
-            .map(|date| date.with_timezone(&get_timezone()))
-        {
+        if let Some(date) = this.local_date_time() {
             return Ok((date.hour() as f64).into());
         } else {
             return Ok(f64::NAN.into());
@@ -512,10 +514,7 @@ pub fn date<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this.and_then(|this| this.as_date_object()) {
-        if let Some(date) = this
-            .date_time()
-            .map(|date| date.with_timezone(&get_timezone()))
-        {
+        if let Some(date) = this.local_date_time() {
             return Ok((date.day() as f64).into());
         } else {
             return Ok(f64::NAN.into());
@@ -547,10 +546,7 @@ pub fn month<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this.and_then(|this| this.as_date_object()) {
-        if let Some(date) = this
-            .date_time()
-            .map(|date| date.with_timezone(&get_timezone()))
-        {
+        if let Some(date) = this.local_date_time() {
             return Ok((date.month0() as f64).into());
         } else {
             return Ok(f64::NAN.into());
@@ -583,10 +579,7 @@ pub fn full_year<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this.and_then(|this| this.as_date_object()) {
-        if let Some(date) = this
-            .date_time()
-            .map(|date| date.with_timezone(&get_timezone()))
-        {
+        if let Some(date) = this.local_date_time() {
             return Ok((date.year() as f64).into());
         } else {
             return Ok(f64::NAN.into());
@@ -620,10 +613,7 @@ pub fn day<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this.and_then(|this| this.as_date_object()) {
-        if let Some(date) = this
-            .date_time()
-            .map(|date| date.with_timezone(&get_timezone()))
-        {
+        if let Some(date) = this.local_date_time() {
             return Ok((date.weekday().num_days_from_sunday() as f64).into());
         } else {
             return Ok(f64::NAN.into());
@@ -890,10 +880,7 @@ pub fn timezone_offset<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this.and_then(|this| this.as_date_object()) {
-        if let Some(date) = this
-            .date_time()
-            .map(|date| date.with_timezone(&get_timezone()))
-        {
+        if let Some(date) = this.local_date_time() {
             let offset = date.offset().utc_minus_local() as f64;
             return Ok((offset / 60.0).into());
         } else {
@@ -930,16 +917,17 @@ pub fn utc<'gc>(
 }
 
 /// Implements the `toString` method.
+///
+/// Flash always uses English day and month abbreviations here, whatever the
+/// system locale. chrono's `%a` and `%b` are not localized either, so they
+/// match exactly.
 pub fn to_string<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this.and_then(|this| this.as_date_object()) {
-        if let Some(date) = this
-            .date_time()
-            .map(|date| date.with_timezone(&get_timezone()))
-        {
+        if let Some(date) = this.local_date_time() {
             return Ok(AvmString::new_utf8(
                 activation.context.gc_context,
                 date.format("%a %b %-d %T GMT%z %-Y").to_string(),
@@ -981,13 +969,11 @@ pub fn to_locale_string<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this.and_then(|this| this.as_date_object()) {
-        if let Some(date) = this
-            .date_time()
-            .map(|date| date.with_timezone(&get_timezone()))
-        {
+        if let Some(date) = this.local_date_time() {
             return Ok(AvmString::new_utf8(
                 activation.context.gc_context,
-                date.format("%a %b %-d %-Y %T %p").to_string(),
+                date.format(&activation.context.locale.date_time_format())
+                    .to_string(),
             )
             .into());
         } else {
@@ -1005,10 +991,7 @@ pub fn to_time_string<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this.and_then(|this| this.as_date_object()) {
-        if let Some(date) = this
-            .date_time()
-            .map(|date| date.with_timezone(&get_timezone()))
-        {
+        if let Some(date) = this.local_date_time() {
             return Ok(AvmString::new_utf8(
                 activation.context.gc_context,
                 date.format("%T GMT%z").to_string(),
@@ -1029,13 +1012,11 @@ pub fn to_locale_time_string<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this.and_then(|this| this.as_date_object()) {
-        if let Some(date) = this
-            .date_time()
-            .map(|date| date.with_timezone(&get_timezone()))
-        {
+        if let Some(date) = this.local_date_time() {
             return Ok(AvmString::new_utf8(
                 activation.context.gc_context,
-                date.format("%T %p").to_string(),
+                date.format(activation.context.locale.time_format())
+                    .to_string(),
             )
             .into());
         } else {
@@ -1046,17 +1027,14 @@ pub fn to_locale_time_string<'gc>(
     Ok(Value::Undefined)
 }
 
-/// Implements the `toDateString` & `toLocaleDateString` method.
+/// Implements the `toDateString` method.
 pub fn to_date_string<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this.and_then(|this| this.as_date_object()) {
-        if let Some(date) = this
-            .date_time()
-            .map(|date| date.with_timezone(&get_timezone()))
-        {
+        if let Some(date) = this.local_date_time() {
             return Ok(AvmString::new_utf8(
                 activation.context.gc_context,
                 date.format("%a %b %-d %-Y").to_string(),
@@ -1070,6 +1048,28 @@ pub fn to_date_string<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements the `toLocaleDateString` method.
+pub fn to_locale_date_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this.and_then(|this| this.as_date_object()) {
+        if let Some(date) = this.local_date_time() {
+            return Ok(AvmString::new_utf8(
+                activation.context.gc_context,
+                date.format(activation.context.locale.date_format())
+                    .to_string(),
+            )
+            .into());
+        } else {
+            return Ok("Invalid Date".into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Parse a date, in any of the three formats: YYYY/MM/DD, MM/DD/YYYY, Mon/DD/YYYY.
 /// The output will always be: (year, month, day), or None if format is invalid.
 fn parse_date(item: &WStr) -> Option<(u32, u32, u32)> {
@@ -1168,6 +1168,136 @@ fn parse_hms(item: &WStr) -> Option<(u32, u32, u32)> {
     ))
 }
 
+/// A date parsed from an ISO 8601 string, such as `2021-03-14T15:09:26.535Z`.
+struct IsoDate {
+    year: u32,
+    month: u32,
+    day: u32,
+    /// The hours, minutes, seconds and milliseconds, if a time was given.
+    time: Option<(u32, u32, u32, u32)>,
+    /// The offset from a `Z` or `+HH:MM`/`-HH:MM` suffix, which can only follow a time.
+    offset: Option<FixedOffset>,
+}
+
+/// Whether this item is made up only of ASCII digits, so that signs and spaces are rejected.
+fn is_digits(item: &WStr) -> bool {
+    !item.is_empty()
+        && item
+            .iter()
+            .all(|c| (b'0' as u16..=b'9' as u16).contains(&c))
+}
+
+/// Parses a number that must be exactly `len` digits long.
+fn parse_digits(item: &WStr, len: usize) -> Option<u32> {
+    if item.len() != len || !is_digits(item) {
+        return None;
+    }
+    item.parse::<u32>().ok()
+}
+
+/// Parses a `+HH:MM` or `-HH:MM` timezone offset.
+fn parse_iso_offset(item: &WStr) -> Option<FixedOffset> {
+    let sign = item.get(0)?;
+    let mut iter = item.slice(1..)?.split(b':');
+    let hours = parse_digits(iter.next()?, 2)?;
+    let minutes = parse_digits(iter.next()?, 2)?;
+    if iter.next().is_some() {
+        // the iterator should have been empty
+        return None;
+    }
+    let seconds = ((hours * 60 + minutes) * 60) as i32;
+    if sign == b'+' as u16 {
+        FixedOffset::east_opt(seconds)
+    } else if sign == b'-' as u16 {
+        FixedOffset::west_opt(seconds)
+    } else {
+        None
+    }
+}
+
+/// Parses an ISO 8601 date: YYYY-MM-DD, optionally followed by THH:MM, THH:MM:SS or THH:MM:SS.sss.
+/// The time may end with `Z` or a `+HH:MM`/`-HH:MM` offset.
+/// Any number of fractional digits is allowed, but anything below a millisecond is truncated.
+fn parse_iso(item: &WStr) -> Option<IsoDate> {
+    let (date, time) = match item.find(b'T') {
+        Some(index) => {
+            let (date, time) = item.split_at(index);
+            (date, Some(time.slice(1..)?))
+        }
+        None => (item, None),
+    };
+
+    let mut iter = date.split(b'-');
+    let year = parse_digits(iter.next()?, 4)?;
+    let month = parse_digits(iter.next()?, 2)?.checked_sub(1)?;
+    let day = parse_digits(iter.next()?, 2)?;
+    if iter.next().is_some() {
+        // the iterator should have been empty
+        return None;
+    }
+
+    let mut parsed = IsoDate {
+        year,
+        month,
+        day,
+        time: None,
+        offset: None,
+    };
+    let time = if let Some(time) = time {
+        time
+    } else {
+        return Some(parsed);
+    };
+
+    let time = if let Some(time) = time.strip_suffix(b'Z') {
+        parsed.offset = Some(FixedOffset::east(0));
+        time
+    } else if let Some(index) = time.find(|c| c == b'+' as u16 || c == b'-' as u16) {
+        let (time, offset) = time.split_at(index);
+        parsed.offset = Some(parse_iso_offset(offset)?);
+        time
+    } else {
+        time
+    };
+
+    let (time, fraction) = match time.find(b'.') {
+        Some(index) => {
+            let (time, fraction) = time.split_at(index);
+            (time, Some(fraction.slice(1..)?))
+        }
+        None => (time, None),
+    };
+
+    let mut iter = time.split(b':');
+    let hours = parse_digits(iter.next()?, 2)?;
+    let minutes = parse_digits(iter.next()?, 2)?;
+    let seconds = match iter.next() {
+        Some(seconds) => parse_digits(seconds, 2)?,
+        // A fraction is only allowed after the seconds.
+        None if fraction.is_some() => return None,
+        None => 0,
+    };
+    if iter.next().is_some() {
+        // the iterator should have been empty
+        return None;
+    }
+
+    let milliseconds = match fraction {
+        Some(fraction) => {
+            if !is_digits(fraction) {
+                return None;
+            }
+            // `.5` is 500 milliseconds, and `.123456` is truncated to 123.
+            let digits = fraction.len().min(3);
+            parse_digits(fraction.slice(..digits)?, digits)? * 10u32.pow(3 - digits as u32)
+        }
+        None => 0,
+    };
+
+    parsed.time = Some((hours, minutes, seconds, milliseconds));
+    Some(parsed)
+}
+
 /// Implements the `parse` class method.
 // False
 #[allow(clippy::question_mark)]
@@ -1191,7 +1321,42 @@ pub fn parse<'gc>(
         .split(string_utils::swf_is_whitespace)
         .filter(|s| !s.is_empty())
     {
-        if let Some((year, month, day)) = parse_date(item) {
+        if let Some(iso) = parse_iso(item) {
+            // Parse YYYY-MM-DDTHH:MM:SS.sssZ
+
+            if final_time.year.is_some() || final_time.month.is_some() || final_time.day.is_some() {
+                return Ok(f64::NAN.into());
+            }
+            final_time.year = Some(Some(iso.year as f64));
+            final_time.month = Some(Some(iso.month as f64));
+            final_time.day = Some(Some(iso.day as f64));
+
+            if let Some((hours, minutes, seconds, milliseconds)) = iso.time {
+                if final_time.hour.is_some()
+                    || final_time.minute.is_some()
+                    || final_time.second.is_some()
+                {
+                    return Ok(f64::NAN.into());
+                }
+                final_time.hour = Some(Some(hours as f64));
+                final_time.minute = Some(Some(minutes as f64));
+                final_time.second = Some(Some(seconds as f64));
+                final_time.millisecond = Some(Some(milliseconds as f64));
+            }
+
+            // As in ECMA-262, a date-time without an offset is in local time,
+            // but a date on its own is in UTC.
+            let offset = match (iso.offset, iso.time) {
+                (None, None) => Some(FixedOffset::east(0)),
+                (offset, _) => offset,
+            };
+            if let Some(offset) = offset {
+                if new_timezone.is_some() {
+                    return Ok(f64::NAN.into());
+                }
+                new_timezone = Some(offset);
+            }
+        } else if let Some((year, month, day)) = parse_date(item) {
             // Parse YYYY/MM/DD, MM/DD/YYYY, Mon/DD/YYYY
 
             // First we check if the fields we are going to set have already been set, if they are, we return NaN.
@@ -1285,6 +1450,7 @@ pub fn parse<'gc>(
     }
     if let Some(timestamp) = final_time.calculate(
         new_timezone
+            .map(LocalTimezone::from)
             .unwrap_or(timezone)
             .ymd(0, 1, 1)
             .and_hms(0, 0, 0),
@@ -1346,3 +1512,174 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
 
     class
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::set_timezone_override;
+    use crate::player::{PlayerBuilder, NEWEST_PLAYER_VERSION};
+    use crate::tag_utils::SwfMovie;
+    use std::time::Instant;
+
+    /// Runs `test` with a new `Date` set to 2001-02-03 04:05:06 UTC.
+    fn with_date<F>(test: F)
+    where
+        F: for<'gc> FnOnce(&mut Activation<'_, 'gc, '_>, Object<'gc>) -> Result<(), Error>,
+    {
+        let player = PlayerBuilder::new()
+            .with_movie(SwfMovie::empty(NEWEST_PLAYER_VERSION))
+            .build();
+        let mut player = player.lock().unwrap();
+        player.mutate_with_update_context(|context| {
+            let mut activation = Activation::from_nothing(context.reborrow());
+            let timestamp = Utc.ymd(2001, 2, 3).and_hms(4, 5, 6).timestamp_millis() as f64;
+            let date = activation
+                .avm2()
+                .classes()
+                .date
+                .construct(&mut activation, &[timestamp.into()])
+                .unwrap();
+            if let Err(e) = test(&mut activation, date) {
+                panic!("Encountered exception during test: {}", e);
+            }
+        })
+    }
+
+    /// Calls one of the native `Date` methods on `date`, returning its result as a number.
+    fn call<'gc>(
+        activation: &mut Activation<'_, 'gc, '_>,
+        method: NativeMethodImpl,
+        date: Object<'gc>,
+        args: &[Value<'gc>],
+    ) -> Result<f64, Error> {
+        method(activation, Some(date), args)?.coerce_to_number(activation)
+    }
+
+    #[test]
+    fn local_fields_follow_timezone_and_setters() {
+        with_date(|activation, this| {
+            // The tests run in a fixed +05:45 timezone.
+            assert_eq!(call(activation, hours, this, &[])?, 9.0);
+            assert_eq!(call(activation, minutes, this, &[])?, 50.0);
+
+            set_timezone_override(Some(FixedOffset::west(5 * 3600).into()));
+            let date_west = call(activation, date, this, &[]);
+            let hours_west = call(activation, hours, this, &[]);
+            set_timezone_override(None);
+            assert_eq!(date_west?, 2.0);
+            assert_eq!(hours_west?, 23.0);
+
+            assert_eq!(call(activation, hours, this, &[])?, 9.0);
+            call(activation, set_hours, this, &[1.into()])?;
+            assert_eq!(call(activation, hours, this, &[])?, 1.0);
+            assert_eq!(call(activation, minutes, this, &[])?, 50.0);
+            Ok(())
+        });
+    }
+
+    /// Times reading all six local fields of a date, as scripts formatting a date do.
+    ///
+    /// Run with `cargo test --release -p ruffle_core local_field_getters -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn local_field_getters() {
+        with_date(|activation, this| {
+            const ITERATIONS: u32 = 100_000;
+            let getters: [NativeMethodImpl; 6] = [full_year, month, date, hours, minutes, seconds];
+            let start = Instant::now();
+            for _ in 0..ITERATIONS {
+                for getter in getters {
+                    call(activation, getter, this, &[])?;
+                }
+            }
+            let elapsed = start.elapsed();
+            println!(
+                "{} reads of six local fields: {:?} ({:?} each)",
+                ITERATIONS,
+                elapsed,
+                elapsed / ITERATIONS
+            );
+            Ok(())
+        });
+    }
+
+    /// Times `getTime`, which reads the stored UTC timestamp, against `getFullYear`,
+    /// which has to convert it into the local timezone first. Only the first of these
+    /// conversions does any work, as `DateObject::local_date_time` caches it.
+    ///
+    /// Run with `cargo test --release -p ruffle_core get_time_against_full_year -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn get_time_against_full_year() {
+        with_date(|activation, this| {
+            const ITERATIONS: u32 = 100_000;
+            let getters: [(&str, NativeMethodImpl); 2] =
+                [("getTime", time), ("getFullYear", full_year)];
+            for (name, getter) in getters {
+                let start = Instant::now();
+                for _ in 0..ITERATIONS {
+                    call(activation, getter, this, &[])?;
+                }
+                let elapsed = start.elapsed();
+                println!(
+                    "{} {} calls: {:?} ({:?} each)",
+                    ITERATIONS,
+                    name,
+                    elapsed,
+                    elapsed / ITERATIONS
+                );
+            }
+            Ok(())
+        });
+    }
+
+    /// Times setting the year, month and day with three setter calls, as scripts often
+    /// do, against setting all three with a single `setFullYear` call.
+    ///
+    /// The tests use a fixed timezone, so see `locale::tests::host_timezone_lookup` for
+    /// the cost of finding the host's timezone, which every setter call adds.
+    ///
+    /// Run with `cargo test --release -p ruffle_core chained_setters -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn chained_setters() {
+        with_date(|activation, this| {
+            const ITERATIONS: u32 = 100_000;
+
+            let start = Instant::now();
+            for i in 0..ITERATIONS {
+                let day = f64::from(i % 28 + 1);
+                call(activation, set_full_year, this, &[2010.into()])?;
+                call(activation, set_month, this, &[5.into()])?;
+                call(activation, set_date, this, &[day.into()])?;
+            }
+            let chained = start.elapsed();
+
+            let start = Instant::now();
+            for i in 0..ITERATIONS {
+                let day = f64::from(i % 28 + 1);
+                call(
+                    activation,
+                    set_full_year,
+                    this,
+                    &[2010.into(), 5.into(), day.into()],
+                )?;
+            }
+            let batched = start.elapsed();
+
+            println!(
+                "{} setFullYear/setMonth/setDate chains: {:?} ({:?} each)",
+                ITERATIONS,
+                chained,
+                chained / ITERATIONS
+            );
+            println!(
+                "{} setFullYear(year, month, day) calls: {:?} ({:?} each)",
+                ITERATIONS,
+                batched,
+                batched / ITERATIONS
+            );
+            Ok(())
+        });
+    }
+}
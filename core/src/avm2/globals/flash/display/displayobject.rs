@@ -1,16 +1,20 @@
 //! `flash.display.DisplayObject` builtin/prototype
 
 use crate::avm2::activation::Activation;
+use crate::avm2::array::ArrayStorage;
 use crate::avm2::class::Class;
+use crate::avm2::globals::flash::geom::transform::{object_to_color_transform, object_to_matrix};
+use crate::avm2::globals::NS_RUFFLE_INTERNAL;
 use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::object::{stage_allocator, Object, TObject};
+use crate::avm2::traits::Trait;
 use crate::avm2::value::Value;
 use crate::avm2::ArrayObject;
 use crate::avm2::Error;
 use crate::avm2::Multiname;
 use crate::avm2::Namespace;
 use crate::avm2::QName;
-use crate::display_object::{HitTestOptions, TDisplayObject};
+use crate::display_object::{DisplayObject, HitTestOptions, PerspectiveProjection, TDisplayObject};
 use crate::ecma_conversions::round_to_even;
 use crate::frame_lifecycle::catchup_display_object_to_frame;
 use crate::string::AvmString;
@@ -144,15 +148,12 @@ pub fn set_height<'gc>(
 
 /// Implements `scaleY`'s getter.
 pub fn scale_y<'gc>(
-    activation: &mut Activation<'_, 'gc, '_>,
+    _activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
-        return Ok(dobj
-            .scale_y(activation.context.gc_context)
-            .into_unit()
-            .into());
+        return Ok(dobj.scale_y().into_unit().into());
     }
 
     Ok(Value::Undefined)
@@ -170,7 +171,11 @@ pub fn set_scale_y<'gc>(
             .cloned()
             .unwrap_or(Value::Undefined)
             .coerce_to_number(activation)?;
-        dobj.set_scale_y(activation.context.gc_context, Percent::from_unit(new_scale));
+
+        // Non-finite scales are ignored, rather than poisoning the matrix.
+        if new_scale.is_finite() {
+            dobj.set_scale_y(activation.context.gc_context, Percent::from_unit(new_scale));
+        }
     }
 
     Ok(Value::Undefined)
@@ -212,15 +217,12 @@ pub fn set_width<'gc>(
 
 /// Implements `scaleX`'s getter.
 pub fn scale_x<'gc>(
-    activation: &mut Activation<'_, 'gc, '_>,
+    _activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
-        return Ok(dobj
-            .scale_x(activation.context.gc_context)
-            .into_unit()
-            .into());
+        return Ok(dobj.scale_x().into_unit().into());
     }
 
     Ok(Value::Undefined)
@@ -238,33 +240,157 @@ pub fn set_scale_x<'gc>(
             .cloned()
             .unwrap_or(Value::Undefined)
             .coerce_to_number(activation)?;
-        dobj.set_scale_x(activation.context.gc_context, Percent::from_unit(new_scale));
+
+        // Non-finite scales are ignored, rather than poisoning the matrix.
+        if new_scale.is_finite() {
+            dobj.set_scale_x(activation.context.gc_context, Percent::from_unit(new_scale));
+        }
     }
 
     Ok(Value::Undefined)
 }
 
 /// Implements `filters`'s getter.
+///
+/// Like Flash, this returns a new array of copies of the filters each time,
+/// so modifying the result has no effect until it is assigned back.
 pub fn filters<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
-    _this: Option<Object<'gc>>,
+    this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    log::warn!("DisplayObject.filters getter - not yet implemented");
+    if let Some(this) = this {
+        let filters = this.get_property(
+            &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "filters").into(),
+            activation,
+        )?;
+        if let Value::Object(filters) = filters {
+            return Ok(clone_filters(activation, filters)?.into());
+        }
+    }
+
     Ok(ArrayObject::empty(activation)?.into())
 }
 
 /// Implements `filters`'s setter.
+///
+/// The filters are copied on assignment. Filters aren't rendered yet, so they
+/// are only stored for the getter.
 pub fn set_filters<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Option<Object<'gc>>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let filters = match args.get(0).cloned().unwrap_or(Value::Null) {
+            Value::Null | Value::Undefined => Value::Null,
+            filters => {
+                let filters = clone_filters(activation, filters.coerce_to_object(activation)?)?;
+                if has_filters(Some(filters)) {
+                    filters.into()
+                } else {
+                    Value::Null
+                }
+            }
+        };
+
+        this.set_property(
+            &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "filters").into(),
+            filters,
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Create a new array containing a `clone()` of each filter in `filters`.
+fn clone_filters<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    filters: Object<'gc>,
+) -> Result<Object<'gc>, Error> {
+    let filters: Vec<_> = filters
+        .as_array_storage()
+        .ok_or("TypeError: Error #1034: Type Coercion failed: filters must be an Array.")?
+        .iter()
+        .collect();
+
+    let mut cloned = Vec::with_capacity(filters.len());
+    for filter in filters {
+        let filter = filter
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?
+            .call_property(
+                &QName::new(Namespace::public(), "clone").into(),
+                &[],
+                activation,
+            )?;
+        cloned.push(filter);
+    }
+
+    ArrayObject::from_storage(activation, ArrayStorage::from_args(&cloned))
+}
+
+/// Whether `filters` is a non-empty filter array.
+fn has_filters(filters: Option<Object<'_>>) -> bool {
+    filters
+        .and_then(|filters| {
+            filters
+                .as_array_storage()
+                .map(|filters| filters.length() > 0)
+        })
+        .unwrap_or(false)
+}
+
+/// Implements `cacheAsBitmap`'s getter.
+///
+/// An object with filters is always cached as a bitmap, regardless of the
+/// value that was explicitly set.
+pub fn cache_as_bitmap<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    log::warn!("DisplayObject.filters setter - not yet implemented");
+    if let Some(this) = this {
+        let filters = this
+            .get_property(
+                &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "filters").into(),
+                activation,
+            )?
+            .as_object();
+        if has_filters(filters) {
+            return Ok(true.into());
+        }
+
+        if let Some(dobj) = this.as_display_object() {
+            return Ok(dobj.is_bitmap_cached().into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `cacheAsBitmap`'s setter.
+pub fn set_cache_as_bitmap<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let cache_as_bitmap = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+        dobj.set_is_bitmap_cached(activation.context.gc_context, cache_as_bitmap);
+    }
+
     Ok(Value::Undefined)
 }
 
 /// Implements `x`'s getter.
+///
+/// Positions are stored in twips, so this is always a multiple of 1/20 of a pixel.
 pub fn x<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
@@ -278,6 +404,9 @@ pub fn x<'gc>(
 }
 
 /// Implements `x`'s setter.
+///
+/// Like Flash, the new value is truncated towards zero to the nearest twip
+/// (1/20 of a pixel), so setting `x = 10.06` reads back as `10.05`.
 pub fn set_x<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
@@ -297,6 +426,8 @@ pub fn set_x<'gc>(
 }
 
 /// Implements `y`'s getter.
+///
+/// Positions are stored in twips, so this is always a multiple of 1/20 of a pixel.
 pub fn y<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
@@ -310,6 +441,9 @@ pub fn y<'gc>(
 }
 
 /// Implements `y`'s setter.
+///
+/// Like Flash, the new value is truncated towards zero to the nearest twip
+/// (1/20 of a pixel), so setting `y = 10.06` reads back as `10.05`.
 pub fn set_y<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
@@ -330,37 +464,41 @@ pub fn set_y<'gc>(
 
 /// Implements `rotation`'s getter.
 pub fn rotation<'gc>(
-    activation: &mut Activation<'_, 'gc, '_>,
+    _activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
-        let rot: f64 = dobj.rotation(activation.context.gc_context).into();
-        let rem = rot % 360.0;
-
-        if rem <= 180.0 {
-            return Ok(rem.into());
-        } else {
-            return Ok((rem - 360.0).into());
-        }
+        let rotation: f64 = dobj.rotation().into();
+        return Ok(rotation.into());
     }
 
     Ok(Value::Undefined)
 }
 
 /// Implements `rotation`'s setter.
+///
+/// The value isn't quantized, so fractional degrees read back unchanged; it is only
+/// normalized into the range [-180, 180], as in AVM1.
 pub fn set_rotation<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
-        let new_rotation = args
+        let mut new_rotation = args
             .get(0)
             .cloned()
             .unwrap_or(Value::Undefined)
             .coerce_to_number(activation)?;
 
+        new_rotation %= 360.0;
+        if new_rotation < -180.0 {
+            new_rotation += 360.0
+        } else if new_rotation > 180.0 {
+            new_rotation -= 360.0
+        }
+
         dobj.set_rotation(activation.context.gc_context, Degrees::from(new_rotation));
     }
 
@@ -381,6 +519,10 @@ pub fn name<'gc>(
 }
 
 /// Implements `name`'s setter.
+///
+/// Duplicate names are allowed. Containers look children up by name on
+/// demand, so the new name is visible to `getChildByName` immediately, with
+/// the first matching child in render order winning any collision.
 pub fn set_name<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
@@ -393,12 +535,19 @@ pub fn set_name<'gc>(
             .unwrap_or(Value::Undefined)
             .coerce_to_string(activation)?;
 
+        // Objects created by scripts can be renamed freely.
         if dobj.instantiated_by_timeline() {
-            return Err(format!(
-                "Display object {} was placed by the timeline and cannot have it's name changed.",
-                new_name
-            )
-            .into());
+            let message =
+                "Error #2078: The name property of a Timeline-placed object cannot be modified.";
+            let error = activation
+                .avm2()
+                .classes()
+                .illegaloperationerror
+                .construct(activation, &[message.into(), 2078.into()])?;
+            // `IllegalOperationError` doesn't override `Error.name`.
+            return Err(activation
+                .avm2()
+                .throw_value(error.into(), format!("Error: {}", message)));
         }
 
         dobj.set_name(activation.context.gc_context, new_name);
@@ -424,6 +573,9 @@ pub fn parent<'gc>(
 }
 
 /// Implements `root`.
+///
+/// This is `null` unless the object is attached to the root of a movie, either
+/// on the stage or in a loaded SWF that hasn't been added to the stage yet.
 pub fn root<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
@@ -488,6 +640,9 @@ pub fn set_visible<'gc>(
 }
 
 /// Implements `mouseX`.
+///
+/// This honors the scroll rect that was last rendered, not one set since; a
+/// new `scrollRect` only takes effect on the next render.
 pub fn mouse_x<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
@@ -518,6 +673,10 @@ pub fn mouse_y<'gc>(
 }
 
 /// Implements `hitTestPoint`.
+///
+/// The point is given in stage coordinates, regardless of where this object
+/// sits in the display list; both hit test methods compare it against world
+/// space bounds and shapes.
 pub fn hit_test_point<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
@@ -558,6 +717,212 @@ pub fn hit_test_point<'gc>(
     Ok(Value::Undefined)
 }
 
+/// The identity matrix, in the column-major order of `Matrix3D.rawData`.
+const IDENTITY_3D: [f64; 16] = [
+    1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+];
+
+/// Multiplies two column-major 4x4 matrices, so that `rhs` is applied first.
+fn multiply_3d(lhs: &[f64; 16], rhs: &[f64; 16]) -> [f64; 16] {
+    let mut result = [0.0; 16];
+    for column in 0..4 {
+        for row in 0..4 {
+            result[column * 4 + row] = (0..4).map(|i| lhs[i * 4 + row] * rhs[column * 4 + i]).sum();
+        }
+    }
+    result
+}
+
+/// The 3D transform from the local space of `dobj` into stage space.
+///
+/// Objects without a `matrix3D` contribute their 2D matrix, which leaves `z` unchanged.
+fn concatenated_matrix_3d(dobj: DisplayObject<'_>) -> [f64; 16] {
+    let mut result = IDENTITY_3D;
+    let mut node = Some(dobj);
+    while let Some(obj) = node {
+        let local = obj.current_matrix3d().unwrap_or_else(|| {
+            let matrix = *obj.base().matrix();
+            let mut local = IDENTITY_3D;
+            local[0] = matrix.a.into();
+            local[1] = matrix.b.into();
+            local[4] = matrix.c.into();
+            local[5] = matrix.d.into();
+            local[12] = matrix.tx.to_pixels();
+            local[13] = matrix.ty.to_pixels();
+            local
+        });
+        result = multiply_3d(&local, &result);
+        node = obj.parent();
+    }
+    result
+}
+
+/// The perspective projection used for `dobj`, as its focal length and its center.
+///
+/// This is the projection of `dobj` or its closest ancestor that has one. If none
+/// has one, the stage's default projection is used.
+fn effective_projection(
+    activation: &mut Activation<'_, '_, '_>,
+    dobj: DisplayObject<'_>,
+) -> (f64, (f64, f64)) {
+    let (stage_width, stage_height) = activation.context.stage.stage_size();
+    let mut node = Some(dobj);
+    let projection = loop {
+        match node {
+            Some(obj) => match obj.perspective_projection() {
+                Some(projection) => break projection,
+                None => node = obj.parent(),
+            },
+            None => {
+                break PerspectiveProjection {
+                    field_of_view: 55.0,
+                    projection_center: (
+                        f64::from(stage_width) / 2.0,
+                        f64::from(stage_height) / 2.0,
+                    ),
+                }
+            }
+        }
+    };
+
+    let focal_length =
+        (f64::from(stage_width) / 2.0) / (projection.field_of_view.to_radians() / 2.0).tan();
+    (focal_length, projection.projection_center)
+}
+
+/// Implements `globalToLocal3D`.
+///
+/// The point is unprojected into a ray from the eye of the effective perspective
+/// projection, and the result is where that ray meets the `z = 0` plane of this
+/// object. If that plane is seen edge-on, all components of the result are `NaN`.
+pub fn global_to_local_3d<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let point = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let x = point
+            .get_property(&Multiname::public("x"), activation)?
+            .coerce_to_number(activation)?;
+        let y = point
+            .get_property(&Multiname::public("y"), activation)?
+            .coerce_to_number(activation)?;
+
+        let m = concatenated_matrix_3d(dobj);
+        let (focal_length, (center_x, center_y)) = effective_projection(activation, dobj);
+
+        // Intersect the ray from the eye through the point with the plane of this object.
+        let x_axis = [m[0], m[1], m[2]];
+        let y_axis = [m[4], m[5], m[6]];
+        let origin = [m[12], m[13], m[14]];
+        let normal = [
+            x_axis[1] * y_axis[2] - x_axis[2] * y_axis[1],
+            x_axis[2] * y_axis[0] - x_axis[0] * y_axis[2],
+            x_axis[0] * y_axis[1] - x_axis[1] * y_axis[0],
+        ];
+        let eye = [center_x, center_y, -focal_length];
+        let direction = [x - center_x, y - center_y, focal_length];
+        let dot = |a: [f64; 3], b: [f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+        let denominator = dot(normal, direction);
+        if denominator == 0.0 {
+            return Ok(activation
+                .avm2()
+                .classes()
+                .vector3d
+                .construct(
+                    activation,
+                    &[f64::NAN.into(), f64::NAN.into(), f64::NAN.into()],
+                )?
+                .into());
+        }
+        let t = dot(
+            normal,
+            [origin[0] - eye[0], origin[1] - eye[1], origin[2] - eye[2]],
+        ) / denominator;
+        let hit = [
+            eye[0] + t * direction[0] - origin[0],
+            eye[1] + t * direction[1] - origin[1],
+            eye[2] + t * direction[2] - origin[2],
+        ];
+
+        // Express the hit in terms of the axes, using the two rows where they are the
+        // most independent. For 2D objects, these are `x` and `y`.
+        let (i, j) = if normal[2].abs() >= normal[0].abs() && normal[2].abs() >= normal[1].abs() {
+            (0, 1)
+        } else if normal[0].abs() >= normal[1].abs() {
+            (1, 2)
+        } else {
+            (2, 0)
+        };
+        let minor = x_axis[i] * y_axis[j] - x_axis[j] * y_axis[i];
+        let x = (hit[i] * y_axis[j] - hit[j] * y_axis[i]) / minor;
+        let y = (x_axis[i] * hit[j] - x_axis[j] * hit[i]) / minor;
+
+        return Ok(activation
+            .avm2()
+            .classes()
+            .vector3d
+            .construct(activation, &[x.into(), y.into(), 0.into()])?
+            .into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `local3DToGlobal`.
+///
+/// The point is moved into stage space through the 3D transforms of this object
+/// and its ancestors, and then projected onto the `z = 0` plane using the
+/// effective perspective projection.
+pub fn local_3d_to_global<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let point = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let x = point
+            .get_property(&Multiname::public("x"), activation)?
+            .coerce_to_number(activation)?;
+        let y = point
+            .get_property(&Multiname::public("y"), activation)?
+            .coerce_to_number(activation)?;
+        let z = point
+            .get_property(&Multiname::public("z"), activation)?
+            .coerce_to_number(activation)?;
+
+        let m = concatenated_matrix_3d(dobj);
+        let (x, y, z) = (
+            m[0] * x + m[4] * y + m[8] * z + m[12],
+            m[1] * x + m[5] * y + m[9] * z + m[13],
+            m[2] * x + m[6] * y + m[10] * z + m[14],
+        );
+
+        let (focal_length, (center_x, center_y)) = effective_projection(activation, dobj);
+        let scale = focal_length / (focal_length + z);
+        let x = center_x + (x - center_x) * scale;
+        let y = center_y + (y - center_y) * scale;
+
+        return Ok(activation
+            .avm2()
+            .classes()
+            .point
+            .construct(activation, &[x.into(), y.into()])?
+            .into());
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Implements `hitTestObject`.
 pub fn hit_test_object<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
@@ -579,6 +944,80 @@ pub fn hit_test_object<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `DisplayObject.getBounds`
+///
+/// A rendered `scrollRect` replaces the content bounds, so the result is clipped to it.
+pub fn get_bounds<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let target = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .as_object()
+            .and_then(|o| o.as_display_object())
+            .unwrap_or(dobj);
+
+        let bounds = dobj.bounds();
+        let rect_args = if !bounds.valid {
+            // An object with no content reports an empty rectangle at its own origin,
+            // but in any other coordinate space Flash reports this sentinel position
+            // (0x8000000 twips) instead of transforming the origin.
+            if DisplayObject::ptr_eq(dobj, target) {
+                [0.into(), 0.into(), 0.into(), 0.into()]
+            } else {
+                let sentinel = Twips::new(0x8000000).to_pixels();
+                [sentinel.into(), sentinel.into(), 0.into(), 0.into()]
+            }
+        } else {
+            let out_bounds = if DisplayObject::ptr_eq(dobj, target) {
+                bounds
+            } else {
+                // Like AVM1, transform the AABB rather than recomputing a tighter one,
+                // which matches Flash's behavior.
+                // A scroll rect's bounds already start at its top-left corner, so we go through
+                // our parent rather than `local_to_global_matrix`, which applies the scroll offset.
+                let to_parent_global = dobj
+                    .parent()
+                    .map(|parent| parent.local_to_global_matrix())
+                    .unwrap_or_default();
+                let bounds_transform =
+                    target.global_to_local_matrix() * to_parent_global * *dobj.base().matrix();
+                bounds.transform(&bounds_transform)
+            };
+            [
+                out_bounds.x_min.to_pixels().into(),
+                out_bounds.y_min.to_pixels().into(),
+                out_bounds.width().to_pixels().into(),
+                out_bounds.height().to_pixels().into(),
+            ]
+        };
+
+        return Ok(activation
+            .avm2()
+            .classes()
+            .rectangle
+            .construct(activation, &rect_args)?
+            .into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `DisplayObject.getRect`
+pub fn get_rect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    // TODO: This should get the bounds ignoring strokes. Always equal to or smaller than getBounds.
+    // Just defer to getBounds for now, as AVM1 does.
+    get_bounds(activation, this, args)
+}
+
 /// Implements `loaderInfo` getter
 pub fn loader_info<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
@@ -589,6 +1028,8 @@ pub fn loader_info<'gc>(
         // Contrary to the DisplayObject.loaderInfo documentation,
         // Flash Player defines 'loaderInfo' for non-root DisplayObjects.
         // It always returns the LoaderInfo from the root object.
+        // For content loaded through a `Loader`, that's the root of the loaded
+        // movie, since `avm2_root` stops at the first `is_root` clip it finds.
         if let Some(loader_info) = dobj
             .avm2_root(&mut activation.context)
             .and_then(|root_dobj| root_dobj.loader_info())
@@ -600,6 +1041,10 @@ pub fn loader_info<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `DisplayObject.transform`'s getter.
+///
+/// Like Flash, every read constructs a new `Transform`, but each one refers back to this
+/// display object rather than holding a copy, so they all observe and modify the same state.
 pub fn transform<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
@@ -621,29 +1066,45 @@ pub fn set_transform<'gc>(
     this: Option<Object<'gc>>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    if let Some(this) = this {
-        let transform = args[0].coerce_to_object(activation)?;
-
-        // FIXME - consider 3D matrix and pixel bounds
-        let matrix = transform
-            .get_property(&QName::dynamic_name("matrix").into(), activation)?
-            .coerce_to_object(activation)?;
-        let color_transform = transform
-            .get_property(&QName::dynamic_name("matrix").into(), activation)?
-            .coerce_to_object(activation)?;
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let transform = match args.get(0).cloned().unwrap_or(Value::Undefined) {
+            Value::Null | Value::Undefined => {
+                return Err("TypeError: Error #2007: Parameter transform must be non-null.".into());
+            }
+            transform => transform.coerce_to_object(activation)?,
+        };
 
+        // FIXME - consider 3D matrix (pixel bounds are read-only, so there is nothing to copy)
+        // A missing `matrix` or `colorTransform` (`matrix` is null for 3D objects)
+        // leaves that part of our own transform untouched.
         let matrix =
-            crate::avm2::globals::flash::geom::transform::object_to_matrix(matrix, activation)?;
-        let color_transform =
-            crate::avm2::globals::flash::geom::transform::object_to_color_transform(
-                color_transform,
-                activation,
-            )?;
-
-        let dobj = this.as_display_object().unwrap();
-        let mut write = dobj.base_mut(activation.context.gc_context);
-        write.set_color_transform(&color_transform);
-        write.set_matrix(&matrix);
+            match transform.get_property(&QName::dynamic_name("matrix").into(), activation)? {
+                Value::Object(matrix) => Some(object_to_matrix(matrix, activation)?),
+                _ => None,
+            };
+        let color_transform = match transform
+            .get_property(&QName::dynamic_name("colorTransform").into(), activation)?
+        {
+            Value::Object(color_transform) => {
+                Some(object_to_color_transform(color_transform, activation)?)
+            }
+            _ => None,
+        };
+
+        // Only the parts that differ from our own are applied. Re-applying an unchanged
+        // matrix would re-derive scale and rotation from it, losing e.g. a negative `scaleX`.
+        if let Some(color_transform) = color_transform {
+            if color_transform != *dobj.base().color_transform() {
+                // Like `alpha`, this stops the timeline from overwriting the color transform.
+                dobj.set_color_transform(activation.context.gc_context, &color_transform);
+                dobj.set_transformed_by_script(activation.context.gc_context, true);
+            }
+        }
+        if let Some(matrix) = matrix {
+            if matrix != *dobj.base().matrix() {
+                dobj.set_matrix(activation.context.gc_context, &matrix);
+            }
+        }
     }
     Ok(Value::Undefined)
 }
@@ -762,6 +1223,123 @@ fn set_scroll_rect<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `mask`'s getter.
+pub fn mask<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        return Ok(dobj
+            .masker()
+            .map(|masker| masker.object2())
+            .unwrap_or(Value::Null));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `mask`'s setter.
+pub fn set_mask<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let mask = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .as_object()
+            .and_then(|o| o.as_display_object());
+        let context = &mut activation.context;
+        dobj.set_clip_depth(context.gc_context, 0);
+        dobj.set_masker(context.gc_context, mask, true);
+        if let Some(mask) = mask {
+            mask.set_maskee(context.gc_context, Some(dobj), true);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `accessibilityProperties`'s getter.
+pub fn accessibility_properties<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return this.get_property(
+            &QName::new(
+                Namespace::private(NS_RUFFLE_INTERNAL),
+                "accessibilityProperties",
+            )
+            .into(),
+            activation,
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `accessibilityProperties`'s setter.
+///
+/// We don't have an accessibility implementation, so this just remembers the
+/// value for the getter.
+pub fn set_accessibility_properties<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        this.set_property(
+            &QName::new(
+                Namespace::private(NS_RUFFLE_INTERNAL),
+                "accessibilityProperties",
+            )
+            .into(),
+            args.get(0).cloned().unwrap_or(Value::Null),
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `metaData`'s getter.
+pub fn meta_data<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return this.get_property(
+            &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "metaData").into(),
+            activation,
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `metaData`'s setter.
+pub fn set_meta_data<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        this.set_property(
+            &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "metaData").into(),
+            args.get(0).cloned().unwrap_or(Value::Null),
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Construct `DisplayObject`'s class.
 pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
     let class = Class::new(
@@ -806,14 +1384,58 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         ("mouseY", Some(mouse_y), None),
         ("loaderInfo", Some(loader_info), None),
         ("filters", Some(filters), Some(set_filters)),
+        (
+            "cacheAsBitmap",
+            Some(cache_as_bitmap),
+            Some(set_cache_as_bitmap),
+        ),
         ("transform", Some(transform), Some(set_transform)),
         ("scrollRect", Some(scroll_rect), Some(set_scroll_rect)),
+        ("mask", Some(mask), Some(set_mask)),
+        (
+            "accessibilityProperties",
+            Some(accessibility_properties),
+            Some(set_accessibility_properties),
+        ),
+        ("metaData", Some(meta_data), Some(set_meta_data)),
     ];
     write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
 
+    // Slot for the `accessibilityProperties` value, which we store but otherwise ignore.
+    write.define_instance_trait(Trait::from_slot(
+        QName::new(
+            Namespace::private(NS_RUFFLE_INTERNAL),
+            "accessibilityProperties",
+        ),
+        QName::new(
+            Namespace::package("flash.accessibility"),
+            "AccessibilityProperties",
+        )
+        .into(),
+        None,
+    ));
+
+    // Slot for the copies of the filters assigned to `filters`.
+    write.define_instance_trait(Trait::from_slot(
+        QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "filters"),
+        QName::new(Namespace::public(), "Array").into(),
+        None,
+    ));
+
+    // Slot for the `metaData` object, as attached to instances by the Flash authoring tool.
+    write.define_instance_trait(Trait::from_slot(
+        QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "metaData"),
+        QName::new(Namespace::public(), "Object").into(),
+        None,
+    ));
+
     const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] = &[
         ("hitTestPoint", hit_test_point),
         ("hitTestObject", hit_test_object),
+        ("getBounds", get_bounds),
+        ("getRect", get_rect),
+        ("globalToLocal3D", global_to_local_3d),
+        ("local3DToGlobal", local_3d_to_global),
     ];
     write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
 
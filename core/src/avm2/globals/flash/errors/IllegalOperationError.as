@@ -1,5 +1,5 @@
 package flash.errors {
-	public dynamic class IllegalOperationError {
+	public dynamic class IllegalOperationError extends Error {
 		// Despite what the documentation claims, user code can pass in an 'id'
 		// parameter (which defaults to 0)
 		public function IllegalOperationError(message:String = "", id:int = 0) {
@@ -1,8 +1,8 @@
 ﻿package flash.filters {
 	public final class BlurFilter extends BitmapFilter {
-		public var blurX: Number;
-		public var blurY: Number;
-		public var quality: int;
+		private var _blurX: Number;
+		private var _blurY: Number;
+		private var _quality: int;
 
 		public function BlurFilter(blurX: Number = 4.0, blurY: Number = 4.0, quality: int = 1) {
 			this.blurX = blurX;
@@ -10,6 +10,30 @@
 			this.quality = quality;
 		}
 
+		public function get blurX(): Number {
+			return this._blurX;
+		}
+
+		public function set blurX(value: Number): void {
+			this._blurX = Math.max(0, Math.min(255, value));
+		}
+
+		public function get blurY(): Number {
+			return this._blurY;
+		}
+
+		public function set blurY(value: Number): void {
+			this._blurY = Math.max(0, Math.min(255, value));
+		}
+
+		public function get quality(): int {
+			return this._quality;
+		}
+
+		public function set quality(value: int): void {
+			this._quality = Math.max(1, Math.min(3, value));
+		}
+
 		override public function clone(): BitmapFilter {
 			return new BlurFilter(this.blurX, this.blurY, this.quality);
 		}
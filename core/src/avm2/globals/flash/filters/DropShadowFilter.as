@@ -0,0 +1,69 @@
+﻿package flash.filters {
+	public final class DropShadowFilter extends BitmapFilter {
+		public var alpha: Number;
+		private var _angle: Number;
+		private var _blurX: Number;
+		private var _blurY: Number;
+		public var color: uint;
+		public var distance: Number;
+		public var hideObject: Boolean;
+		public var inner: Boolean;
+		public var knockout: Boolean;
+		private var _quality: int;
+		public var strength: Number;
+
+		public function DropShadowFilter(distance: Number = 4.0, angle: Number = 45, color: uint = 0, alpha: Number = 1.0, blurX: Number = 4.0, blurY: Number = 4.0, strength: Number = 1.0, quality: int = 1, inner: Boolean = false, knockout: Boolean = false, hideObject: Boolean = false) {
+			this.alpha = alpha;
+			this.angle = angle;
+			this.blurX = blurX;
+			this.blurY = blurY;
+			this.color = color;
+			this.distance = distance;
+			this.hideObject = hideObject;
+			this.inner = inner;
+			this.knockout = knockout;
+			this.quality = quality;
+			this.strength = strength;
+		}
+
+		public function get angle(): Number {
+			return this._angle;
+		}
+
+		public function set angle(value: Number): void {
+			value %= 360;
+			if (value < 0) {
+				value += 360;
+			}
+			this._angle = value;
+		}
+
+		public function get blurX(): Number {
+			return this._blurX;
+		}
+
+		public function set blurX(value: Number): void {
+			this._blurX = Math.max(0, Math.min(255, value));
+		}
+
+		public function get blurY(): Number {
+			return this._blurY;
+		}
+
+		public function set blurY(value: Number): void {
+			this._blurY = Math.max(0, Math.min(255, value));
+		}
+
+		public function get quality(): int {
+			return this._quality;
+		}
+
+		public function set quality(value: int): void {
+			this._quality = Math.max(1, Math.min(3, value));
+		}
+
+		override public function clone(): BitmapFilter {
+			return new DropShadowFilter(this.distance, this.angle, this.color, this.alpha, this.blurX, this.blurY, this.strength, this.quality, this.inner, this.knockout, this.hideObject);
+		}
+	}
+}
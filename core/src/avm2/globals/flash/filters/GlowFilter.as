@@ -1,12 +1,12 @@
 ﻿package flash.filters {
 	public final class GlowFilter extends BitmapFilter {
 		public var alpha: Number;
-		public var blurX: Number;
-		public var blurY: Number;
+		private var _blurX: Number;
+		private var _blurY: Number;
 		public var color: uint;
 		public var inner: Boolean;
 		public var knockout: Boolean;
-		public var quality: int;
+		private var _quality: int;
 		public var strength: Number;
 
 		public function GlowFilter(color: uint = 0xFF0000, alpha: Number = 1.0, blurX: Number = 6.0, blurY: Number = 6.0, strength: Number = 2, quality: int = 1, inner: Boolean = false, knockout: Boolean = false) {
@@ -20,6 +20,30 @@
 			this.strength = strength;
 		}
 
+		public function get blurX(): Number {
+			return this._blurX;
+		}
+
+		public function set blurX(value: Number): void {
+			this._blurX = Math.max(0, Math.min(255, value));
+		}
+
+		public function get blurY(): Number {
+			return this._blurY;
+		}
+
+		public function set blurY(value: Number): void {
+			this._blurY = Math.max(0, Math.min(255, value));
+		}
+
+		public function get quality(): int {
+			return this._quality;
+		}
+
+		public function set quality(value: int): void {
+			this._quality = Math.max(1, Math.min(3, value));
+		}
+
 		override public function clone(): BitmapFilter {
 			return new GlowFilter(this.color, this.alpha, this.blurX, this.blurY, this.strength, this.quality, this.inner, this.knockout);
 		}
@@ -126,7 +126,22 @@ package flash.geom {
 		}
 
 		public function invert():void {
+			if (this.b == 0 && this.c == 0) {
+				// Fast path for matrices without rotation or skew.
+				this.a = 1 / this.a;
+				this.d = 1 / this.d;
+				this.tx = -this.a * this.tx;
+				this.ty = -this.d * this.ty;
+				return;
+			}
+
 			var det = this.a * this.d - this.c * this.b;
+			if (det == 0) {
+				// A singular matrix can't be inverted, so Flash resets it instead.
+				this.identity();
+				return;
+			}
+
 			var tx = (this.d * this.tx - this.c * this.ty) / -det;
 			var ty = (this.b * this.tx - this.a * this.ty) / det;
 			var a = this.d / det;
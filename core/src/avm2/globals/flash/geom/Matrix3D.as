@@ -0,0 +1,268 @@
+package flash.geom {
+    public class Matrix3D {
+
+        // The 4x4 matrix data, stored in column-major order
+        // (so the translation lives in elements 12, 13 and 14).
+        private var _rawData:Vector.<Number>;
+
+        public function Matrix3D(v:Vector.<Number> = null) {
+            this._rawData = new Vector.<Number>(16, true);
+            if (v != null) {
+                this.rawData = v;
+            } else {
+                this.identity();
+            }
+        }
+
+        public function get rawData():Vector.<Number> {
+            var copy:Vector.<Number> = new Vector.<Number>(16);
+            for (var i:uint = 0; i < 16; i++) {
+                copy[i] = this._rawData[i];
+            }
+            return copy;
+        }
+
+        public function set rawData(v:Vector.<Number>):void {
+            for (var i:uint = 0; i < 16; i++) {
+                this._rawData[i] = v[i];
+            }
+        }
+
+        public function get position():Vector3D {
+            return new Vector3D(this._rawData[12], this._rawData[13], this._rawData[14]);
+        }
+
+        public function set position(pos:Vector3D):void {
+            this._rawData[12] = pos.x;
+            this._rawData[13] = pos.y;
+            this._rawData[14] = pos.z;
+        }
+
+        public function clone():Matrix3D {
+            return new Matrix3D(this._rawData);
+        }
+
+        public function copyFrom(sourceMatrix3D:Matrix3D):void {
+            this.rawData = sourceMatrix3D._rawData;
+        }
+
+        public function copyColumnFrom(column:uint, vector3D:Vector3D):void {
+            if (column < 4) {
+                this._rawData[column * 4] = vector3D.x;
+                this._rawData[column * 4 + 1] = vector3D.y;
+                this._rawData[column * 4 + 2] = vector3D.z;
+                this._rawData[column * 4 + 3] = vector3D.w;
+            } // otherwise matrix is unchanged
+        }
+
+        public function copyColumnTo(column:uint, vector3D:Vector3D):void {
+            if (column < 4) {
+                vector3D.x = this._rawData[column * 4];
+                vector3D.y = this._rawData[column * 4 + 1];
+                vector3D.z = this._rawData[column * 4 + 2];
+                vector3D.w = this._rawData[column * 4 + 3];
+            } // otherwise vector is unchanged
+        }
+
+        public function copyRowFrom(row:uint, vector3D:Vector3D):void {
+            if (row < 4) {
+                this._rawData[row] = vector3D.x;
+                this._rawData[row + 4] = vector3D.y;
+                this._rawData[row + 8] = vector3D.z;
+                this._rawData[row + 12] = vector3D.w;
+            } // otherwise matrix is unchanged
+        }
+
+        public function copyRowTo(row:uint, vector3D:Vector3D):void {
+            if (row < 4) {
+                vector3D.x = this._rawData[row];
+                vector3D.y = this._rawData[row + 4];
+                vector3D.z = this._rawData[row + 8];
+                vector3D.w = this._rawData[row + 12];
+            } // otherwise vector is unchanged
+        }
+
+        public function identity():void {
+            for (var i:uint = 0; i < 16; i++) {
+                this._rawData[i] = (i % 5 == 0) ? 1 : 0;
+            }
+        }
+
+        // Equivalent to `this = lhs * this`.
+        public function append(lhs:Matrix3D):void {
+            this._rawData = multiply(lhs._rawData, this._rawData);
+        }
+
+        // Equivalent to `this = this * rhs`.
+        public function prepend(rhs:Matrix3D):void {
+            this._rawData = multiply(this._rawData, rhs._rawData);
+        }
+
+        public function appendRotation(degrees:Number, axis:Vector3D, pivotPoint:Vector3D = null):void {
+            this.append(rotation(degrees, axis, pivotPoint));
+        }
+
+        public function prependRotation(degrees:Number, axis:Vector3D, pivotPoint:Vector3D = null):void {
+            this.prepend(rotation(degrees, axis, pivotPoint));
+        }
+
+        // Equivalent to `this = translation * this`: the translation is applied
+        // after the existing transformation, i.e. in world space.
+        public function appendTranslation(x:Number, y:Number, z:Number):void {
+            for (var col:uint = 0; col < 4; col++) {
+                var w:Number = this._rawData[col * 4 + 3];
+                this._rawData[col * 4] += x * w;
+                this._rawData[col * 4 + 1] += y * w;
+                this._rawData[col * 4 + 2] += z * w;
+            }
+        }
+
+        // Equivalent to `this = this * translation`: the translation is applied
+        // before the existing transformation, i.e. in local space.
+        public function prependTranslation(x:Number, y:Number, z:Number):void {
+            for (var row:uint = 0; row < 4; row++) {
+                this._rawData[12 + row] += x * this._rawData[row]
+                    + y * this._rawData[4 + row]
+                    + z * this._rawData[8 + row];
+            }
+        }
+
+        // Splits this matrix into translation, rotation and scale, in that order.
+        // The rotation holds euler angles in radians, applied about X, then Y, then Z.
+        public function decompose(orientationStyle:String = "eulerAngles"):Vector.<Vector3D> {
+            if (orientationStyle != Orientation3D.EULER_ANGLES) {
+                throw new Error("Matrix3D.decompose is not yet implemented for " + orientationStyle + "!");
+            }
+
+            var raw:Vector.<Number> = this._rawData;
+            var translation:Vector3D = new Vector3D(raw[12], raw[13], raw[14]);
+
+            var scale:Vector3D = new Vector3D(
+                Math.sqrt(raw[0] * raw[0] + raw[1] * raw[1] + raw[2] * raw[2]),
+                Math.sqrt(raw[4] * raw[4] + raw[5] * raw[5] + raw[6] * raw[6]),
+                Math.sqrt(raw[8] * raw[8] + raw[9] * raw[9] + raw[10] * raw[10])
+            );
+            // A mirrored matrix can't be expressed by a rotation, so flip the Z scale.
+            var determinant:Number = raw[0] * (raw[5] * raw[10] - raw[6] * raw[9])
+                - raw[4] * (raw[1] * raw[10] - raw[2] * raw[9])
+                + raw[8] * (raw[1] * raw[6] - raw[2] * raw[5]);
+            if (determinant < 0) {
+                scale.z = -scale.z;
+            }
+
+            var rotation:Vector3D = new Vector3D();
+            if (scale.x != 0 && scale.y != 0 && scale.z != 0) {
+                // The rotation matrix is `Rz * Ry * Rx`, with each column divided by its scale.
+                var sinY:Number = -raw[2] / scale.x;
+                rotation.y = Math.asin(Math.max(-1, Math.min(1, sinY)));
+                if (Math.abs(sinY) < 1) {
+                    rotation.x = Math.atan2(raw[6] / scale.y, raw[10] / scale.z);
+                    rotation.z = Math.atan2(raw[1], raw[0]);
+                } else {
+                    // Gimbal lock: X and Z rotate about the same axis, so put it all in X.
+                    rotation.x = Math.atan2(raw[4] / scale.y * sinY, raw[5] / scale.y);
+                }
+            }
+
+            var components:Vector.<Vector3D> = new Vector.<Vector3D>();
+            components.push(translation, rotation, scale);
+            return components;
+        }
+
+        // The inverse of `decompose`, replacing this matrix with one built from
+        // translation, rotation and scale. Fails if any of the scales are zero.
+        public function recompose(components:Vector.<Vector3D>, orientationStyle:String = "eulerAngles"):Boolean {
+            if (orientationStyle != Orientation3D.EULER_ANGLES) {
+                throw new Error("Matrix3D.recompose is not yet implemented for " + orientationStyle + "!");
+            }
+            if (components == null || components.length < 3) {
+                return false;
+            }
+
+            var translation:Vector3D = components[0];
+            var rotation:Vector3D = components[1];
+            var scale:Vector3D = components[2];
+            if (scale.x == 0 || scale.y == 0 || scale.z == 0) {
+                return false;
+            }
+
+            var cx:Number = Math.cos(rotation.x);
+            var sx:Number = Math.sin(rotation.x);
+            var cy:Number = Math.cos(rotation.y);
+            var sy:Number = Math.sin(rotation.y);
+            var cz:Number = Math.cos(rotation.z);
+            var sz:Number = Math.sin(rotation.z);
+
+            this._rawData[0] = cy * cz * scale.x;
+            this._rawData[1] = cy * sz * scale.x;
+            this._rawData[2] = -sy * scale.x;
+            this._rawData[3] = 0;
+            this._rawData[4] = (sx * sy * cz - cx * sz) * scale.y;
+            this._rawData[5] = (sx * sy * sz + cx * cz) * scale.y;
+            this._rawData[6] = sx * cy * scale.y;
+            this._rawData[7] = 0;
+            this._rawData[8] = (cx * sy * cz + sx * sz) * scale.z;
+            this._rawData[9] = (cx * sy * sz - sx * cz) * scale.z;
+            this._rawData[10] = cx * cy * scale.z;
+            this._rawData[11] = 0;
+            this._rawData[12] = translation.x;
+            this._rawData[13] = translation.y;
+            this._rawData[14] = translation.z;
+            this._rawData[15] = 1;
+            return true;
+        }
+
+        public function transformVector(v:Vector3D):Vector3D {
+            var raw:Vector.<Number> = this._rawData;
+            return new Vector3D(
+                raw[0] * v.x + raw[4] * v.y + raw[8] * v.z + raw[12],
+                raw[1] * v.x + raw[5] * v.y + raw[9] * v.z + raw[13],
+                raw[2] * v.x + raw[6] * v.y + raw[10] * v.z + raw[14],
+                raw[3] * v.x + raw[7] * v.y + raw[11] * v.z + raw[15]
+            );
+        }
+
+        // Multiplies two column-major 4x4 matrices, returning `a * b`.
+        private static function multiply(a:Vector.<Number>, b:Vector.<Number>):Vector.<Number> {
+            var result:Vector.<Number> = new Vector.<Number>(16, true);
+            for (var col:uint = 0; col < 4; col++) {
+                for (var row:uint = 0; row < 4; row++) {
+                    result[col * 4 + row] = a[row] * b[col * 4]
+                        + a[4 + row] * b[col * 4 + 1]
+                        + a[8 + row] * b[col * 4 + 2]
+                        + a[12 + row] * b[col * 4 + 3];
+                }
+            }
+            return result;
+        }
+
+        // Builds a rotation of `degrees` about `axis`, optionally around `pivotPoint`
+        // rather than the origin.
+        private static function rotation(degrees:Number, axis:Vector3D, pivotPoint:Vector3D):Matrix3D {
+            var length:Number = axis.length;
+            var x:Number = axis.x / length;
+            var y:Number = axis.y / length;
+            var z:Number = axis.z / length;
+
+            var radians:Number = degrees * Math.PI / 180;
+            var c:Number = Math.cos(radians);
+            var s:Number = Math.sin(radians);
+            var t:Number = 1 - c;
+
+            var m:Matrix3D = new Matrix3D(Vector.<Number>([
+                t * x * x + c, t * x * y + s * z, t * x * z - s * y, 0,
+                t * x * y - s * z, t * y * y + c, t * y * z + s * x, 0,
+                t * x * z + s * y, t * y * z - s * x, t * z * z + c, 0,
+                0, 0, 0, 1
+            ]));
+
+            if (pivotPoint != null) {
+                // Move the pivot to the origin, rotate, then move it back.
+                m.prependTranslation(-pivotPoint.x, -pivotPoint.y, -pivotPoint.z);
+                m.appendTranslation(pivotPoint.x, pivotPoint.y, pivotPoint.z);
+            }
+
+            return m;
+        }
+    }
+}
@@ -0,0 +1,33 @@
+package flash.geom {
+    public class PerspectiveProjection {
+
+        // Flash derives the focal length and the default projection center from
+        // the size of the stage, just like `DisplayObject.local3DToGlobal` does.
+        private static native function get stageWidth():Number;
+        private static native function get stageHeight():Number;
+
+        private var _fieldOfView:Number = 55;
+
+        public var projectionCenter:Point;
+
+        public function PerspectiveProjection() {
+            this.projectionCenter = new Point(stageWidth / 2, stageHeight / 2);
+        }
+
+        public function get fieldOfView():Number {
+            return this._fieldOfView;
+        }
+
+        public function set fieldOfView(value:Number):void {
+            this._fieldOfView = value;
+        }
+
+        public function get focalLength():Number {
+            return (stageWidth / 2) / Math.tan(this._fieldOfView * Math.PI / 360);
+        }
+
+        public function set focalLength(value:Number):void {
+            this._fieldOfView = Math.atan((stageWidth / 2) / value) * 360 / Math.PI;
+        }
+    }
+}
@@ -12,8 +12,25 @@ package flash.geom {
 		public native function set colorTransform(value: ColorTransform):void;
 		public native function get matrix():Matrix;
 		public native function set matrix(value:Matrix):void;
+		public native function get perspectiveProjection():PerspectiveProjection;
+		public native function set perspectiveProjection(value:PerspectiveProjection):void;
+
+		public function get matrix3D():Matrix3D {
+			var rawData:Vector.<Number> = this.readRawData();
+			return rawData == null ? null : new Matrix3D(rawData);
+		}
+
+		public function set matrix3D(value:Matrix3D):void {
+			this.writeRawData(value == null ? null : value.rawData);
+		}
+
+		// Helpers for `matrix3D`, so its Rust side doesn't depend on how the
+		// native method table would case-convert the `3D` suffix.
+		native function readRawData():Vector.<Number>;
+		native function writeRawData(rawData:Vector.<Number>):void;
 
 		public native function get concatenatedColorTransform():ColorTransform;
 		public native function get concatenatedMatrix():Matrix;
+		public native function get pixelBounds():Rectangle;
 	}
 }
@@ -0,0 +1,27 @@
+//! `flash.geom.PerspectiveProjection` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::object::Object;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+
+/// Implements the private `stageWidth` getter, which the focal length is derived from.
+pub fn get_stage_width<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let (width, _) = activation.context.stage.stage_size();
+    Ok(width.into())
+}
+
+/// Implements the private `stageHeight` getter, which the default projection center
+/// is derived from.
+pub fn get_stage_height<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let (_, height) = activation.context.stage.stage_size();
+    Ok(height.into())
+}
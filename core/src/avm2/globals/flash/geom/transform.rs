@@ -1,7 +1,9 @@
 #![allow(non_snake_case)]
 
+use crate::avm2::object::VectorObject;
+use crate::avm2::vector::VectorStorage;
 use crate::avm2::{Activation, Error, Namespace, Object, QName, TObject, Value};
-use crate::display_object::{StageQuality, TDisplayObject};
+use crate::display_object::{PerspectiveProjection, StageQuality, TDisplayObject};
 use crate::prelude::{ColorTransform, DisplayObject, Matrix, Twips};
 use swf::Fixed8;
 
@@ -33,6 +35,8 @@ pub fn init<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Returns a copy of the object's current color transform; changes only apply once it's
+/// assigned back.
 pub fn get_color_transform<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
@@ -52,9 +56,10 @@ pub fn set_color_transform<'gc>(
 ) -> Result<Value<'gc>, Error> {
     let this = this.unwrap();
     let ct = object_to_color_transform(args[0].coerce_to_object(activation)?, activation)?;
-    get_display_object(this, activation)?
-        .base_mut(activation.context.gc_context)
-        .set_color_transform(&ct);
+    let dobj = get_display_object(this, activation)?;
+    // `alpha` shares this storage, so both must stop the timeline from overwriting it.
+    dobj.set_color_transform(activation.context.gc_context, &ct);
+    dobj.set_transformed_by_script(activation.context.gc_context, true);
     Ok(Value::Undefined)
 }
 
@@ -64,7 +69,12 @@ pub fn get_matrix<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     let this = this.unwrap();
-    let matrix = *get_display_object(this, activation)?.base().matrix();
+    let dobj = get_display_object(this, activation)?;
+    // 3D objects don't have a 2D matrix.
+    if dobj.matrix3d().is_some() {
+        return Ok(Value::Null);
+    }
+    let matrix = *dobj.base().matrix();
     matrix_to_object(matrix, activation)
 }
 
@@ -75,9 +85,109 @@ pub fn set_matrix<'gc>(
 ) -> Result<Value<'gc>, Error> {
     let this = this.unwrap();
     let matrix = object_to_matrix(args[0].coerce_to_object(activation)?, activation)?;
+    let dobj = get_display_object(this, activation)?;
+    // Assigning a 2D matrix turns a 3D object back into a 2D one.
+    dobj.set_matrix3d(activation.context.gc_context, None);
+    dobj.set_matrix(activation.context.gc_context, &matrix);
+    Ok(Value::Undefined)
+}
+
+/// Implements the getter half of `matrix3D`, returning its raw data or `null` for 2D objects.
+pub fn read_raw_data<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let this = this.unwrap();
+    let dobj = get_display_object(this, activation)?;
+    let raw_data = match dobj.current_matrix3d() {
+        Some(raw_data) => raw_data,
+        None => return Ok(Value::Null),
+    };
+
+    let storage = VectorStorage::from_values(
+        raw_data.iter().map(|value| (*value).into()).collect(),
+        false,
+        activation.avm2().classes().number,
+    );
+    Ok(VectorObject::from_vector(storage, activation)?.into())
+}
+
+/// Implements the setter half of `matrix3D`.
+///
+/// Assigning a matrix makes the object 3D, while assigning `null` makes it 2D again,
+/// keeping its current 2D matrix.
+pub fn write_raw_data<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let this = this.unwrap();
+    let dobj = get_display_object(this, activation)?;
+    let raw_data = match args.get(0).cloned().unwrap_or(Value::Null) {
+        Value::Null | Value::Undefined => {
+            dobj.set_matrix3d(activation.context.gc_context, None);
+            return Ok(Value::Undefined);
+        }
+        raw_data => raw_data.coerce_to_object(activation)?,
+    };
+
+    let values: Vec<_> = raw_data
+        .as_vector_storage()
+        .ok_or("TypeError: Error #1034: Type Coercion failed: rawData must be a Vector.")?
+        .iter()
+        .collect();
+    if values.len() != 16 {
+        return Err("ArgumentError: Error #2004: One of the parameters is invalid.".into());
+    }
+
+    let mut matrix3d = [0.0; 16];
+    for (element, value) in matrix3d.iter_mut().zip(values) {
+        *element = value.coerce_to_number(activation)?;
+    }
+
+    // We can only render the 2D part of the transform.
+    let matrix = Matrix {
+        a: matrix3d[0] as f32,
+        b: matrix3d[1] as f32,
+        c: matrix3d[4] as f32,
+        d: matrix3d[5] as f32,
+        tx: Twips::from_pixels(matrix3d[12]),
+        ty: Twips::from_pixels(matrix3d[13]),
+    };
+    dobj.set_matrix(activation.context.gc_context, &matrix);
+    dobj.set_matrix3d(activation.context.gc_context, Some(matrix3d));
+    Ok(Value::Undefined)
+}
+
+pub fn get_perspective_projection<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let this = this.unwrap();
+    let projection = get_display_object(this, activation)?.perspective_projection();
+    match projection {
+        Some(projection) => perspective_projection_to_object(projection, activation),
+        None => Ok(Value::Null),
+    }
+}
+
+pub fn set_perspective_projection<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let this = this.unwrap();
+    let projection = match args[0] {
+        Value::Null | Value::Undefined => None,
+        value => Some(object_to_perspective_projection(
+            value.coerce_to_object(activation)?,
+            activation,
+        )?),
+    };
     get_display_object(this, activation)?
-        .base_mut(activation.context.gc_context)
-        .set_matrix(&matrix);
+        .set_perspective_projection(activation.context.gc_context, projection);
     Ok(Value::Undefined)
 }
 
@@ -99,8 +209,7 @@ pub fn get_concatenated_matrix<'gc>(
 
     // We're a child of the Stage, and not the stage itself
     if node.is_some() && dobj.as_stage().is_none() {
-        let matrix = get_display_object(this, activation)?.local_to_global_matrix();
-        matrix_to_object(matrix, activation)
+        matrix_to_object(dobj.local_to_global_matrix(), activation)
     } else {
         // If this object is the Stage itself, or an object
         // that's not a child of the stage, then we need to mimic
@@ -122,16 +231,56 @@ pub fn get_concatenated_matrix<'gc>(
 }
 
 pub fn get_concatenated_color_transform<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Option<Object<'gc>>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    log::warn!("Transform.concatenatedColorTransform: not yet implemented");
-    Ok(Value::Undefined)
+    let this = this.unwrap();
+
+    let dobj = get_display_object(this, activation)?;
+    let mut color_transform = *dobj.base().color_transform();
+    let mut node = dobj.parent();
+    while let Some(obj) = node {
+        if obj.as_stage().is_some() {
+            break;
+        }
+        color_transform = *obj.base().color_transform() * color_transform;
+        node = obj.parent();
+    }
+
+    color_transform_to_object(&color_transform, activation)
 }
 
-// FIXME - handle clamping. We're throwing away precision here in converting to an integer:
-// is that what we should be doing?
+pub fn get_pixel_bounds<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let this = this.unwrap();
+    let bounds = get_display_object(this, activation)?.world_bounds();
+    let args = if !bounds.valid {
+        // An object with no content has no pixels, wherever it is.
+        [0.into(), 0.into(), 0.into(), 0.into()]
+    } else {
+        [
+            bounds.x_min.to_pixels().into(),
+            bounds.y_min.to_pixels().into(),
+            bounds.width().to_pixels().into(),
+            bounds.height().to_pixels().into(),
+        ]
+    };
+    let object = activation
+        .avm2()
+        .classes()
+        .rectangle
+        .construct(activation, &args)?;
+    Ok(object.into())
+}
+
+/// Reads the eight `ColorTransform` properties of `object` into a display object color transform.
+///
+/// Multipliers are unbounded but quantized to the 8.8 fixed point format display objects use,
+/// and offsets are clamped as per `clamp_color_offset`.
 pub fn object_to_color_transform<'gc>(
     object: Object<'gc>,
     activation: &mut Activation<'_, 'gc, '_>,
@@ -165,13 +314,27 @@ pub fn object_to_color_transform<'gc>(
         g_mult: Fixed8::from_f64(green_multiplier),
         b_mult: Fixed8::from_f64(blue_multiplier),
         a_mult: Fixed8::from_f64(alpha_multiplier),
-        r_add: red_offset as i16,
-        g_add: green_offset as i16,
-        b_add: blue_offset as i16,
-        a_add: alpha_offset as i16,
+        r_add: clamp_color_offset(red_offset),
+        g_add: clamp_color_offset(green_offset),
+        b_add: clamp_color_offset(blue_offset),
+        a_add: clamp_color_offset(alpha_offset),
     })
 }
 
+/// Converts a `ColorTransform` offset into the range Flash stores for display objects.
+///
+/// Offsets outside of -255..255 are clamped, and non-finite values become 0.
+fn clamp_color_offset(offset: f64) -> i16 {
+    if offset.is_finite() {
+        offset.clamp(-255.0, 255.0) as i16
+    } else {
+        0
+    }
+}
+
+/// Builds a `flash.geom.ColorTransform` from a display object's color transform.
+///
+/// This is the inverse of `object_to_color_transform`.
 pub fn color_transform_to_object<'gc>(
     color_transform: &ColorTransform,
     activation: &mut Activation<'_, 'gc, '_>,
@@ -191,6 +354,9 @@ pub fn color_transform_to_object<'gc>(
     Ok(object.into())
 }
 
+/// Builds a `flash.geom.Matrix` from a display object matrix, converting `tx`/`ty` to pixels.
+///
+/// This is the inverse of `object_to_matrix`.
 pub fn matrix_to_object<'gc>(
     matrix: Matrix,
     activation: &mut Activation<'_, 'gc, '_>,
@@ -211,22 +377,35 @@ pub fn matrix_to_object<'gc>(
     Ok(object.into())
 }
 
+/// Reads a `flash.geom.Matrix` into a display object matrix.
+///
+/// The translation is stored in twips, so `tx`/`ty` are truncated to the nearest 1/20th of a
+/// pixel, and reading the matrix back will return the quantized values. Non-finite components
+/// become 0 rather than corrupting the render transform.
 pub fn object_to_matrix<'gc>(
     object: Object<'gc>,
     activation: &mut Activation<'_, 'gc, '_>,
 ) -> Result<Matrix, Error> {
-    let a = object
-        .get_property(&QName::dynamic_name("a").into(), activation)?
-        .coerce_to_number(activation)? as f32;
-    let b = object
-        .get_property(&QName::dynamic_name("b").into(), activation)?
-        .coerce_to_number(activation)? as f32;
-    let c = object
-        .get_property(&QName::dynamic_name("c").into(), activation)?
-        .coerce_to_number(activation)? as f32;
-    let d = object
-        .get_property(&QName::dynamic_name("d").into(), activation)?
-        .coerce_to_number(activation)? as f32;
+    let a = matrix_component(
+        object
+            .get_property(&QName::dynamic_name("a").into(), activation)?
+            .coerce_to_number(activation)?,
+    );
+    let b = matrix_component(
+        object
+            .get_property(&QName::dynamic_name("b").into(), activation)?
+            .coerce_to_number(activation)?,
+    );
+    let c = matrix_component(
+        object
+            .get_property(&QName::dynamic_name("c").into(), activation)?
+            .coerce_to_number(activation)?,
+    );
+    let d = matrix_component(
+        object
+            .get_property(&QName::dynamic_name("d").into(), activation)?
+            .coerce_to_number(activation)?,
+    );
     let tx = Twips::from_pixels(
         object
             .get_property(&QName::dynamic_name("tx").into(), activation)?
@@ -240,3 +419,65 @@ pub fn object_to_matrix<'gc>(
 
     Ok(Matrix { a, b, c, d, tx, ty })
 }
+
+/// Converts a `Matrix` scale/skew component, mapping non-finite values to 0.
+fn matrix_component(value: f64) -> f32 {
+    if value.is_finite() {
+        value as f32
+    } else {
+        0.0
+    }
+}
+
+pub fn perspective_projection_to_object<'gc>(
+    projection: PerspectiveProjection,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Value<'gc>, Error> {
+    let mut object = activation
+        .avm2()
+        .classes()
+        .perspectiveprojection
+        .construct(activation, &[])?;
+    object.set_property(
+        &QName::dynamic_name("fieldOfView").into(),
+        projection.field_of_view.into(),
+        activation,
+    )?;
+
+    let (x, y) = projection.projection_center;
+    let center = activation
+        .avm2()
+        .classes()
+        .point
+        .construct(activation, &[x.into(), y.into()])?;
+    object.set_property(
+        &QName::dynamic_name("projectionCenter").into(),
+        center.into(),
+        activation,
+    )?;
+
+    Ok(object.into())
+}
+
+pub fn object_to_perspective_projection<'gc>(
+    object: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<PerspectiveProjection, Error> {
+    let field_of_view = object
+        .get_property(&QName::dynamic_name("fieldOfView").into(), activation)?
+        .coerce_to_number(activation)?;
+    let center = object
+        .get_property(&QName::dynamic_name("projectionCenter").into(), activation)?
+        .coerce_to_object(activation)?;
+    let x = center
+        .get_property(&QName::dynamic_name("x").into(), activation)?
+        .coerce_to_number(activation)?;
+    let y = center
+        .get_property(&QName::dynamic_name("y").into(), activation)?
+        .coerce_to_number(activation)?;
+
+    Ok(PerspectiveProjection {
+        field_of_view,
+        projection_center: (x, y),
+    })
+}
@@ -2,8 +2,11 @@ use crate::avm2::activation::Activation;
 use crate::avm2::array::ArrayStorage;
 use crate::avm2::bytearray::{ByteArrayStorage, CompressionAlgorithm, Endian, ObjectEncoding};
 use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::globals::date::time_clip;
 use crate::avm2::method::{Method, NativeMethodImpl};
-use crate::avm2::object::{bytearray_allocator, ArrayObject, ByteArrayObject, Object, TObject};
+use crate::avm2::object::{
+    bytearray_allocator, ArrayObject, ByteArrayObject, DateObject, Object, TObject,
+};
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::avm2::Namespace;
@@ -81,7 +84,8 @@ pub fn deserialize_value<'gc>(
             obj.into()
             // TODO: Handle class_defintion
         }
-        // TODO: Dictionary, Vector, XML, Date, etc...
+        AmfValue::Date(time, _) => DateObject::from_date_time(activation, time_clip(*time))?.into(),
+        // TODO: Dictionary, Vector, XML, etc...
         _ => Value::Undefined,
     })
 }
@@ -53,12 +53,16 @@ include "flash/events/MouseEvent.as"
 include "flash/events/KeyboardEvent.as"
 include "flash/errors/IllegalOperationError.as"
 include "flash/filters/BitmapFilter.as"
+include "flash/filters/BitmapFilterQuality.as"
 include "flash/filters/BlurFilter.as"
 include "flash/filters/ColorMatrixFilter.as"
+include "flash/filters/DropShadowFilter.as"
 include "flash/filters/GlowFilter.as"
 include "flash/geom/ColorTransform.as"
 include "flash/geom/Matrix.as"
+include "flash/geom/Matrix3D.as"
 include "flash/geom/Orientation3D.as"
+include "flash/geom/PerspectiveProjection.as"
 include "flash/geom/Point.as"
 include "flash/geom/Rectangle.as"
 include "flash/geom/Transform.as"
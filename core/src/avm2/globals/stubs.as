@@ -17,5 +17,6 @@ include "flash/system/ApplicationDomain.as"
 include "flash/utils/ByteArray.as"
 include "Number.as"
 include "String.as"
+include "Vector.as"
 include "int.as"
 include "uint.as"
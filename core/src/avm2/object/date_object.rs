@@ -3,9 +3,10 @@ use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject};
 use crate::avm2::value::{Hint, Value};
 use crate::avm2::Error;
+use crate::locale::{get_timezone, LocalTimezone};
 use chrono::{DateTime, Utc};
 use gc_arena::{Collect, GcCell, MutationContext};
-use std::cell::{Ref, RefMut};
+use std::cell::{Cell, Ref, RefMut};
 
 /// A class instance allocator that allocates Date objects.
 pub fn date_allocator<'gc>(
@@ -19,6 +20,7 @@ pub fn date_allocator<'gc>(
         DateObjectData {
             base,
             date_time: None,
+            local_date_time: Cell::new(None),
         },
     ))
     .into())
@@ -28,16 +30,68 @@ pub fn date_allocator<'gc>(
 pub struct DateObject<'gc>(GcCell<'gc, DateObjectData<'gc>>);
 
 impl<'gc> DateObject<'gc> {
+    /// Construct a `Date` holding `date_time` directly, without going through the
+    /// AS-facing constructor's argument coercion.
+    ///
+    /// This is intended for internal use, such as deserializing dates.
+    pub fn from_date_time(
+        activation: &mut Activation<'_, 'gc, '_>,
+        date_time: Option<DateTime<Utc>>,
+    ) -> Result<Object<'gc>, Error> {
+        let class = activation.avm2().classes().date;
+        let base = ScriptObjectData::new(class);
+
+        let date = DateObject(GcCell::allocate(
+            activation.context.gc_context,
+            DateObjectData {
+                base,
+                date_time: None,
+                local_date_time: Cell::new(None),
+            },
+        ));
+        let mut instance: Object<'gc> = date.into();
+        instance.install_instance_slots(activation);
+
+        class.call_native_init(Some(instance), &[], activation)?;
+
+        // The constructor defaults to the current time, so this must come afterwards.
+        date.set_date_time(activation.context.gc_context, date_time);
+
+        Ok(instance)
+    }
+
     pub fn date_time(self) -> Option<DateTime<Utc>> {
         self.0.read().date_time
     }
 
+    /// This date in the current local timezone, or `None` if the date is invalid.
+    ///
+    /// Scripts tend to read several local fields back-to-back, so the
+    /// conversion is cached for as long as the timestamp and timezone match.
+    pub fn local_date_time(self) -> Option<DateTime<LocalTimezone>> {
+        let read = self.0.read();
+        let date_time = read.date_time?;
+        let timezone = get_timezone();
+
+        if let Some(cached) = read.local_date_time.get() {
+            if cached.timezone() == timezone && cached.naive_utc() == date_time.naive_utc() {
+                return Some(cached);
+            }
+        }
+
+        let local = date_time.with_timezone(&timezone);
+        read.local_date_time.set(Some(local));
+        Some(local)
+    }
+
     pub fn set_date_time(
         self,
         gc_context: MutationContext<'gc, '_>,
         date_time: Option<DateTime<Utc>>,
     ) {
-        self.0.write(gc_context).date_time = date_time;
+        let mut write = self.0.write(gc_context);
+        write.date_time = date_time;
+        write.local_date_time.set(None);
     }
 }
 
@@ -49,6 +103,10 @@ pub struct DateObjectData<'gc> {
 
     #[collect(require_static)]
     date_time: Option<DateTime<Utc>>,
+
+    /// The last conversion of `date_time` into the local timezone.
+    #[collect(require_static)]
+    local_date_time: Cell<Option<DateTime<LocalTimezone>>>,
 }
 
 impl<'gc> TObject<'gc> for DateObject<'gc> {
@@ -72,6 +130,8 @@ impl<'gc> TObject<'gc> for DateObject<'gc> {
         }
     }
 
+    /// Dates prefer `toString` over `valueOf`, so `date + 1` concatenates, while purely numeric
+    /// operators such as `date - 0` still produce the timestamp.
     fn default_hint(&self) -> Hint {
         Hint::String
     }
@@ -18,6 +18,7 @@ use crate::focus_tracker::FocusTracker;
 use crate::frame_lifecycle::FramePhase;
 use crate::library::Library;
 use crate::loader::LoadManager;
+use crate::locale::Locale;
 use crate::player::Player;
 use crate::prelude::*;
 use crate::tag_utils::{SwfMovie, SwfSlice};
@@ -122,6 +123,9 @@ pub struct UpdateContext<'a, 'gc, 'gc_context> {
     /// The system properties
     pub system: &'a mut SystemProperties,
 
+    /// How the host's locale writes dates and times.
+    pub locale: Locale,
+
     /// The current instance ID. Used to generate default `instanceN` names.
     pub instance_counter: &'a mut i32,
 
@@ -317,6 +321,7 @@ impl<'a, 'gc, 'gc_context> UpdateContext<'a, 'gc, 'gc_context> {
             player: self.player.clone(),
             load_manager: self.load_manager,
             system: self.system,
+            locale: self.locale,
             instance_counter: self.instance_counter,
             shared_objects: self.shared_objects,
             unbound_text_fields: self.unbound_text_fields,
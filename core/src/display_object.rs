@@ -16,7 +16,7 @@ use bitflags::bitflags;
 use gc_arena::{Collect, MutationContext};
 use ruffle_macros::enum_trait_object;
 use ruffle_render::transform::Transform;
-use std::cell::{Ref, RefMut};
+use std::cell::{Cell, Ref, RefMut};
 use std::fmt::Debug;
 use std::sync::Arc;
 use swf::{BlendMode, Fixed8, Rectangle};
@@ -74,6 +74,11 @@ pub struct DisplayObjectBase<'gc> {
     scale_y: Percent,
     skew: f64,
 
+    /// The scale, rotation and skew last derived from the matrix, for reads made while
+    /// the fields above aren't cached. Cleared whenever the matrix is replaced.
+    #[collect(require_static)]
+    derived_scale_rotation: Cell<Option<(Degrees, Percent, Percent, f64)>>,
+
     /// The previous display object in order of AVM1 execution.
     ///
     /// `None` in an AVM2 movie.
@@ -118,6 +123,32 @@ pub struct DisplayObjectBase<'gc> {
     /// changes immediately (without needing wait for a render)
     #[collect(require_static)]
     next_scroll_rect: Option<Rectangle>,
+
+    /// The perspective projection used for 3D children of this display object,
+    /// as set by `transform.perspectiveProjection`.
+    #[collect(require_static)]
+    perspective_projection: Option<PerspectiveProjection>,
+
+    /// The 3D transform set by `transform.matrix3D`, in column-major order.
+    ///
+    /// An object with this set is considered 3D. The elements it shares with the 2D matrix
+    /// (scale, skew and the x/y translation) are kept in `transform.matrix` instead, so that
+    /// 2D properties such as `x` keep working.
+    #[collect(require_static)]
+    matrix3d: Option<[f64; 16]>,
+}
+
+/// The parameters of a `flash.geom.PerspectiveProjection`.
+///
+/// This is used by `local3DToGlobal` and `globalToLocal3D`. Rendering ignores it, as we
+/// don't render 3D transforms yet.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PerspectiveProjection {
+    /// The field of view, in degrees.
+    pub field_of_view: f64,
+
+    /// The vanishing point of the projection, in pixels.
+    pub projection_center: (f64, f64),
 }
 
 impl<'gc> Default for DisplayObjectBase<'gc> {
@@ -133,6 +164,7 @@ impl<'gc> Default for DisplayObjectBase<'gc> {
             scale_x: Percent::from_unit(1.0),
             scale_y: Percent::from_unit(1.0),
             skew: 0.0,
+            derived_scale_rotation: Cell::new(None),
             prev_avm1_clip: None,
             next_avm1_clip: None,
             masker: None,
@@ -143,6 +175,8 @@ impl<'gc> Default for DisplayObjectBase<'gc> {
             flags: DisplayObjectFlags::VISIBLE,
             scroll_rect: None,
             next_scroll_rect: None,
+            perspective_projection: None,
+            matrix3d: None,
         }
     }
 }
@@ -179,11 +213,13 @@ impl<'gc> DisplayObjectBase<'gc> {
     }
 
     pub fn matrix_mut(&mut self) -> &mut Matrix {
+        self.derived_scale_rotation.set(None);
         &mut self.transform.matrix
     }
 
     pub fn set_matrix(&mut self, matrix: &Matrix) {
         self.transform.matrix = *matrix;
+        self.derived_scale_rotation.set(None);
         self.flags -= DisplayObjectFlags::SCALE_ROTATION_CACHED;
     }
 
@@ -217,49 +253,73 @@ impl<'gc> DisplayObjectBase<'gc> {
         self.transform.matrix.ty = Twips::from_pixels(value)
     }
 
+    /// Calculates the scale and rotation factors implied by this display object's matrix,
+    /// returned as `(rotation, scale_x, scale_y, skew)`.
+    /// Calculating these requires heavy trig ops, so the result is kept until the matrix
+    /// changes, and cached in the fields once `_xscale`, `_yscale` or `_rotation` is set.
+    fn derive_scale_rotation(&self) -> (Degrees, Percent, Percent, f64) {
+        if let Some(derived) = self.derived_scale_rotation.get() {
+            return derived;
+        }
+
+        let (a, b, c, d) = (
+            f64::from(self.transform.matrix.a),
+            f64::from(self.transform.matrix.b),
+            f64::from(self.transform.matrix.c),
+            f64::from(self.transform.matrix.d),
+        );
+        // If this object's transform matrix is:
+        // [[a c tx]
+        //  [b d ty]]
+        // After transformation, the X-axis and Y-axis will turn into the column vectors x' = <a, b> and y' = <c, d>.
+        // We derive the scale, rotation, and skew values from these transformed axes.
+        // The skew value is not exposed by ActionScript, but is remembered internally.
+        // xscale = len(x')
+        // yscale = len(y')
+        // rotation = atan2(b, a)  (the rotation of x' from the normal x-axis).
+        // skew = atan2(-c, d) - atan2(b, a)  (the signed difference between y' and x' rotation)
+
+        // This can produce some surprising results due to the overlap between flipping/rotation/skewing.
+        // For example, in Flash, using Modify->Transform->Flip Horizontal and then tracing _xscale, _yscale, and _rotation
+        // will output 100, 100, and 180. (a horizontal flip could also be a 180 degree skew followed by 180 degree rotation!)
+        let rotation_x = f64::atan2(b, a);
+        let rotation_y = f64::atan2(-c, d);
+        let scale_x = f64::sqrt(a * a + b * b);
+        let scale_y = f64::sqrt(c * c + d * d);
+        let derived = (
+            Degrees::from_radians(rotation_x),
+            Percent::from_unit(scale_x),
+            Percent::from_unit(scale_y),
+            rotation_y - rotation_x,
+        );
+        self.derived_scale_rotation.set(Some(derived));
+        derived
+    }
+
     /// Caches the scale and rotation factors for this display object, if necessary.
-    /// Calculating these requires heavy trig ops, so we only do it when `_xscale`, `_yscale` or
-    /// `_rotation` is accessed.
     fn cache_scale_rotation(&mut self) {
         if !self
             .flags
             .contains(DisplayObjectFlags::SCALE_ROTATION_CACHED)
         {
-            let (a, b, c, d) = (
-                f64::from(self.transform.matrix.a),
-                f64::from(self.transform.matrix.b),
-                f64::from(self.transform.matrix.c),
-                f64::from(self.transform.matrix.d),
-            );
-            // If this object's transform matrix is:
-            // [[a c tx]
-            //  [b d ty]]
-            // After transformation, the X-axis and Y-axis will turn into the column vectors x' = <a, b> and y' = <c, d>.
-            // We derive the scale, rotation, and skew values from these transformed axes.
-            // The skew value is not exposed by ActionScript, but is remembered internally.
-            // xscale = len(x')
-            // yscale = len(y')
-            // rotation = atan2(b, a)  (the rotation of x' from the normal x-axis).
-            // skew = atan2(-c, d) - atan2(b, a)  (the signed difference between y' and x' rotation)
-
-            // This can produce some surprising results due to the overlap between flipping/rotation/skewing.
-            // For example, in Flash, using Modify->Transform->Flip Horizontal and then tracing _xscale, _yscale, and _rotation
-            // will output 100, 100, and 180. (a horizontal flip could also be a 180 degree skew followed by 180 degree rotation!)
-            let rotation_x = f64::atan2(b, a);
-            let rotation_y = f64::atan2(-c, d);
-            let scale_x = f64::sqrt(a * a + b * b);
-            let scale_y = f64::sqrt(c * c + d * d);
-            self.rotation = Degrees::from_radians(rotation_x);
-            self.scale_x = Percent::from_unit(scale_x);
-            self.scale_y = Percent::from_unit(scale_y);
-            self.skew = rotation_y - rotation_x;
+            let (rotation, scale_x, scale_y, skew) = self.derive_scale_rotation();
+            self.rotation = rotation;
+            self.scale_x = scale_x;
+            self.scale_y = scale_y;
+            self.skew = skew;
             self.flags |= DisplayObjectFlags::SCALE_ROTATION_CACHED;
         }
     }
 
-    fn rotation(&mut self) -> Degrees {
-        self.cache_scale_rotation();
-        self.rotation
+    fn rotation(&self) -> Degrees {
+        if self
+            .flags
+            .contains(DisplayObjectFlags::SCALE_ROTATION_CACHED)
+        {
+            self.rotation
+        } else {
+            self.derive_scale_rotation().0
+        }
     }
 
     fn set_rotation(&mut self, degrees: Degrees) {
@@ -277,9 +337,15 @@ impl<'gc> DisplayObjectBase<'gc> {
         matrix.d = (self.scale_y.into_unit() * cos_y) as f32;
     }
 
-    fn scale_x(&mut self) -> Percent {
-        self.cache_scale_rotation();
-        self.scale_x
+    fn scale_x(&self) -> Percent {
+        if self
+            .flags
+            .contains(DisplayObjectFlags::SCALE_ROTATION_CACHED)
+        {
+            self.scale_x
+        } else {
+            self.derive_scale_rotation().1
+        }
     }
 
     fn set_scale_x(&mut self, value: Percent) {
@@ -293,9 +359,15 @@ impl<'gc> DisplayObjectBase<'gc> {
         matrix.b = (sin * value.into_unit()) as f32;
     }
 
-    fn scale_y(&mut self) -> Percent {
-        self.cache_scale_rotation();
-        self.scale_y
+    fn scale_y(&self) -> Percent {
+        if self
+            .flags
+            .contains(DisplayObjectFlags::SCALE_ROTATION_CACHED)
+        {
+            self.scale_y
+        } else {
+            self.derive_scale_rotation().2
+        }
     }
 
     fn set_scale_y(&mut self, value: Percent) {
@@ -604,6 +676,13 @@ pub trait TDisplayObject<'gc>:
     fn base_mut<'a>(&'a self, mc: MutationContext<'gc, '_>) -> RefMut<'a, DisplayObjectBase<'gc>>;
 
     fn id(&self) -> CharacterId;
+
+    /// The depth of this object in its parent's depth list.
+    ///
+    /// For objects placed by the timeline this is the depth authored in the
+    /// `PlaceObject` tag. Containers order their render lists by it, so it is
+    /// the source of the indices reported by `getChildIndex` for timeline
+    /// children.
     fn depth(&self) -> Depth {
         self.base().depth()
     }
@@ -713,6 +792,11 @@ pub trait TDisplayObject<'gc>:
     }
 
     /// Returns the matrix for transforming from global stage to this object's local space.
+    ///
+    /// If this object or one of its ancestors has collapsed to a matrix that can't be
+    /// inverted, such as one with `scaleX = 0`, every component of the result is
+    /// infinite or NaN. Those coordinates are rounded to 0, so every global point then
+    /// maps to the local origin.
     fn global_to_local_matrix(&self) -> Matrix {
         let mut matrix = self.local_to_global_matrix();
         matrix.invert();
@@ -755,8 +839,8 @@ pub trait TDisplayObject<'gc>:
 
     /// The rotation in degrees this display object in local space.
     /// Returned by the `_rotation`/`rotation` ActionScript properties.
-    fn rotation(&self, gc_context: MutationContext<'gc, '_>) -> Degrees {
-        self.base_mut(gc_context).rotation()
+    fn rotation(&self) -> Degrees {
+        self.base().rotation()
     }
 
     /// Sets the rotation in degrees this display object in local space.
@@ -768,8 +852,8 @@ pub trait TDisplayObject<'gc>:
     /// The X axis scale for this display object in local space.
     /// The normal scale is 100.
     /// Returned by the `_xscale`/`scaleX` ActionScript properties.
-    fn scale_x(&self, gc_context: MutationContext<'gc, '_>) -> Percent {
-        self.base_mut(gc_context).scale_x()
+    fn scale_x(&self) -> Percent {
+        self.base().scale_x()
     }
 
     /// Sets the scale of the X axis for this display object in local space.
@@ -782,8 +866,8 @@ pub trait TDisplayObject<'gc>:
     /// The Y axis scale for this display object in local space.
     /// The normal scale is 1.
     /// Returned by the `_yscale`/`scaleY` ActionScript properties.
-    fn scale_y(&self, gc_context: MutationContext<'gc, '_>) -> Percent {
-        self.base_mut(gc_context).scale_y()
+    fn scale_y(&self) -> Percent {
+        self.base().scale_y()
     }
 
     /// Sets the Y axis scale for this display object in local space.
@@ -795,6 +879,7 @@ pub trait TDisplayObject<'gc>:
 
     /// Gets the pixel width of the AABB containing this display object in local space.
     /// Returned by the ActionScript `_width`/`width` properties.
+    /// Empty objects have invalid bounds, which transform to a zero-sized box, so this is 0.
     fn width(&self) -> f64 {
         let bounds = self.local_bounds();
         (bounds.x_max - bounds.x_min).to_pixels()
@@ -820,9 +905,9 @@ pub trait TDisplayObject<'gc>:
         // It has to do with the length of the sides A, B of an AABB enclosing the object's OBB with sides a, b:
         // A = sin(t) * a + cos(t) * b
         // B = cos(t) * a + sin(t) * b
-        let prev_scale_x = self.scale_x(gc_context).into_unit();
-        let prev_scale_y = self.scale_y(gc_context).into_unit();
-        let rotation = self.rotation(gc_context);
+        let prev_scale_x = self.scale_x().into_unit();
+        let prev_scale_y = self.scale_y().into_unit();
+        let rotation = self.rotation();
         let cos = f64::abs(f64::cos(rotation.into_radians()));
         let sin = f64::abs(f64::sin(rotation.into_radians()));
         let mut new_scale_x = aspect_ratio * (cos * target_scale_x + sin * target_scale_y)
@@ -844,6 +929,7 @@ pub trait TDisplayObject<'gc>:
 
     /// Gets the pixel height of the AABB containing this display object in local space.
     /// Returned by the ActionScript `_height`/`height` properties.
+    /// As with `width`, this is 0 for empty objects.
     fn height(&self) -> f64 {
         let bounds = self.local_bounds();
         (bounds.y_max - bounds.y_min).to_pixels()
@@ -868,9 +954,9 @@ pub trait TDisplayObject<'gc>:
         // It has to do with the length of the sides A, B of an AABB enclosing the object's OBB with sides a, b:
         // A = sin(t) * a + cos(t) * b
         // B = cos(t) * a + sin(t) * b
-        let prev_scale_x = self.scale_x(gc_context).into_unit();
-        let prev_scale_y = self.scale_y(gc_context).into_unit();
-        let rotation = self.rotation(gc_context);
+        let prev_scale_x = self.scale_x().into_unit();
+        let prev_scale_y = self.scale_y().into_unit();
+        let rotation = self.rotation();
         let cos = f64::abs(f64::cos(rotation.into_radians()));
         let sin = f64::abs(f64::sin(rotation.into_radians()));
         let mut new_scale_x =
@@ -893,6 +979,8 @@ pub trait TDisplayObject<'gc>:
     /// The opacity of this display object.
     /// 1 is fully opaque.
     /// Returned by the `_alpha`/`alpha` ActionScript properties.
+    /// This is the alpha multiplier of the color transform rather than a separate value,
+    /// so it always agrees with `transform.colorTransform`.
     fn alpha(&self) -> f64 {
         self.base().alpha()
     }
@@ -952,9 +1040,12 @@ pub trait TDisplayObject<'gc>:
         }
     }
 
+    /// The highest depth that this object masks, if it was placed by the
+    /// timeline as a clipping layer, or 0 otherwise.
     fn clip_depth(&self) -> Depth {
         self.base().clip_depth()
     }
+
     fn set_clip_depth(&self, gc_context: MutationContext<'gc, '_>, depth: Depth) {
         self.base_mut(gc_context).set_clip_depth(depth);
     }
@@ -1054,6 +1145,41 @@ pub trait TDisplayObject<'gc>:
         self.base_mut(gc_context).next_scroll_rect = rect;
     }
 
+    fn perspective_projection(&self) -> Option<PerspectiveProjection> {
+        self.base().perspective_projection
+    }
+
+    fn set_perspective_projection(
+        &self,
+        gc_context: MutationContext<'gc, '_>,
+        projection: Option<PerspectiveProjection>,
+    ) {
+        self.base_mut(gc_context).perspective_projection = projection;
+    }
+
+    fn matrix3d(&self) -> Option<[f64; 16]> {
+        self.base().matrix3d
+    }
+
+    /// The raw data of `transform.matrix3D`, or `None` for 2D objects.
+    fn current_matrix3d(&self) -> Option<[f64; 16]> {
+        let mut raw_data = self.matrix3d()?;
+
+        // The elements shared with the 2D matrix may have changed since, e.g. through `x`.
+        let matrix = *self.base().matrix();
+        raw_data[0] = matrix.a.into();
+        raw_data[1] = matrix.b.into();
+        raw_data[4] = matrix.c.into();
+        raw_data[5] = matrix.d.into();
+        raw_data[12] = matrix.tx.to_pixels();
+        raw_data[13] = matrix.ty.to_pixels();
+        Some(raw_data)
+    }
+
+    fn set_matrix3d(&self, gc_context: MutationContext<'gc, '_>, matrix3d: Option<[f64; 16]>) {
+        self.base_mut(gc_context).matrix3d = matrix3d;
+    }
+
     fn removed(&self) -> bool {
         self.base().removed()
     }
@@ -1427,15 +1553,72 @@ pub trait TDisplayObject<'gc>:
         self.world_bounds().intersects(&other.world_bounds())
     }
 
+    /// Tests if the art of a given object overlaps with the art of this object in global space.
+    ///
+    /// Unlike `hit_test_object`, this looks at the actual shapes. The region where both
+    /// objects' world bounds overlap is sampled once per pixel, so slivers of art thinner
+    /// than a pixel may be missed.
+    fn hit_test_shape_object(
+        &self,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        other: DisplayObject<'gc>,
+    ) -> bool {
+        let bounds = self.world_bounds();
+        let other_bounds = other.world_bounds();
+        if !bounds.intersects(&other_bounds) {
+            return false;
+        }
+
+        // Sample the middle of each pixel, or the middle of the overlap if it's smaller.
+        let samples = |min: Twips, max: Twips| {
+            let (min, max) = (min.get(), max.get());
+            let start = min + ((max - min) / 2).min(Twips::ONE.get() / 2);
+            (start..max.max(start + 1))
+                .step_by(Twips::ONE.get() as usize)
+                .map(Twips::new)
+        };
+
+        let x_min = bounds.x_min.max(other_bounds.x_min);
+        let x_max = bounds.x_max.min(other_bounds.x_max);
+        let y_min = bounds.y_min.max(other_bounds.y_min);
+        let y_max = bounds.y_max.min(other_bounds.y_max);
+        for y in samples(y_min, y_max) {
+            for x in samples(x_min, x_max) {
+                if self.hit_test_shape(context, (x, y), HitTestOptions::AVM_HIT_TEST)
+                    && other.hit_test_shape(context, (x, y), HitTestOptions::AVM_HIT_TEST)
+                {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
     /// Tests if a given stage position point intersects within this object, considering the art.
     fn hit_test_shape(
         &self,
-        _context: &mut UpdateContext<'_, 'gc, '_>,
+        context: &mut UpdateContext<'_, 'gc, '_>,
         pos: (Twips, Twips),
         _options: HitTestOptions,
     ) -> bool {
         // Default to using bounding box.
-        self.hit_test_bounds(pos)
+        self.hit_test_bounds(pos) && self.hit_test_masker(context, pos)
+    }
+
+    /// Tests if a given stage position point lies within the mask of this object.
+    ///
+    /// Only the part of an object inside of its mask can be hit, so every `hit_test_shape`
+    /// implementation checks this. Objects without a mask pass everywhere.
+    fn hit_test_masker(
+        &self,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        pos: (Twips, Twips),
+    ) -> bool {
+        match self.masker() {
+            Some(masker) => masker.hit_test_shape(context, pos, HitTestOptions::SKIP_INVISIBLE),
+            None => true,
+        }
     }
 
     fn post_instantiation(
@@ -1649,6 +1832,9 @@ bitflags! {
         const SKIP_INVISIBLE = 1 << 1;
 
         /// The options used for `hitTest` calls in ActionScript.
+        ///
+        /// Like Flash, these still hit invisible objects, which are only skipped by
+        /// rendering and mouse picking.
         const AVM_HIT_TEST = Self::SKIP_MASK.bits;
 
         /// The options used for mouse picking, such as clicking on buttons.
@@ -1822,3 +2008,111 @@ impl Default for SoundTransform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm2::object::ScriptObject;
+    use crate::player::PlayerBuilder;
+    use ruffle_render::shape_utils::DrawCommand;
+    use swf::FillStyle;
+
+    /// Builds a filled triangle with the given corners, in pixels.
+    fn triangle<'gc>(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        points: [(f64, f64); 3],
+    ) -> DisplayObject<'gc> {
+        let object = ScriptObject::custom_object(context.gc_context, None, None);
+        let graphic = Graphic::new_with_avm2(context, object);
+        let mut drawing = graphic.as_drawing(context.gc_context).unwrap();
+        drawing.set_fill_style(Some(FillStyle::Color(Color {
+            r: 255,
+            g: 0,
+            b: 0,
+            a: 255,
+        })));
+        let (x, y) = points[0];
+        drawing.draw_command(DrawCommand::MoveTo {
+            x: Twips::from_pixels(x),
+            y: Twips::from_pixels(y),
+        });
+        for (x, y) in points.into_iter().skip(1).chain(std::iter::once(points[0])) {
+            drawing.draw_command(DrawCommand::LineTo {
+                x: Twips::from_pixels(x),
+                y: Twips::from_pixels(y),
+            });
+        }
+        drawing.set_fill_style(None);
+        drop(drawing);
+        graphic.into()
+    }
+
+    #[test]
+    fn hit_test_shape_object_ignores_overlapping_bounds() {
+        let player = PlayerBuilder::new()
+            .with_movie(SwfMovie::empty(NEWEST_PLAYER_VERSION))
+            .build();
+        let mut player = player.lock().unwrap();
+        player.mutate_with_update_context(|context| {
+            // The bounds of these triangles overlap, but the triangles themselves don't.
+            let top_left = triangle(context, [(0.0, 0.0), (100.0, 0.0), (0.0, 100.0)]);
+            let bottom_right = triangle(context, [(100.0, 20.0), (100.0, 100.0), (20.0, 100.0)]);
+            assert!(top_left.hit_test_object(bottom_right));
+            assert!(!top_left.hit_test_shape_object(context, bottom_right));
+            assert!(!bottom_right.hit_test_shape_object(context, top_left));
+
+            // Moving one of them up and to the left makes the shapes overlap.
+            bottom_right.set_x(context.gc_context, -30.0);
+            bottom_right.set_y(context.gc_context, -30.0);
+            assert!(top_left.hit_test_shape_object(context, bottom_right));
+            assert!(bottom_right.hit_test_shape_object(context, top_left));
+        });
+    }
+
+    /// Builds a chain of `depth` nested movie clips under `root`, each offset by one pixel,
+    /// and returns the innermost one.
+    fn nest<'gc>(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        root: DisplayObject<'gc>,
+        depth: usize,
+    ) -> DisplayObject<'gc> {
+        let mut parent = root;
+        for _ in 0..depth {
+            let node: DisplayObject<'gc> =
+                MovieClip::new(context.swf.clone(), context.gc_context).into();
+            node.set_x(context.gc_context, 1.0);
+            let mut container = parent.as_container().unwrap();
+            let index = container.num_children();
+            container.insert_at_index(context, node, index);
+            parent = node;
+        }
+        parent
+    }
+
+    #[test]
+    fn ancestor_bounds_follow_grandchild_changes() {
+        let player = PlayerBuilder::new()
+            .with_movie(SwfMovie::empty(NEWEST_PLAYER_VERSION))
+            .build();
+        let mut player = player.lock().unwrap();
+        player.mutate_with_update_context(|context| {
+            let root: DisplayObject<'_> =
+                MovieClip::new(context.swf.clone(), context.gc_context).into();
+            let child = nest(context, root, 1);
+            let grandchild = triangle(context, [(0.0, 0.0), (10.0, 0.0), (0.0, 10.0)]);
+            child
+                .as_container()
+                .unwrap()
+                .insert_at_index(context, grandchild, 0);
+            assert_eq!(root.width(), 10.0);
+
+            grandchild.set_x(context.gc_context, 20.0);
+            assert_eq!(root.width(), 10.0);
+            assert_eq!(root.world_bounds().x_max, Twips::from_pixels(31.0));
+
+            grandchild.set_width(context.gc_context, 40.0);
+            assert_eq!(root.width(), 40.0);
+            assert_eq!(child.width(), 40.0);
+        });
+    }
+}
@@ -346,6 +346,10 @@ impl<'gc> TDisplayObject<'gc> for Avm1Button<'gc> {
         point: (Twips, Twips),
         options: HitTestOptions,
     ) -> bool {
+        if !self.hit_test_masker(context, point) {
+            return false;
+        }
+
         for child in self.iter_render_list() {
             if child.hit_test_shape(context, point, options) {
                 return true;
@@ -649,7 +649,9 @@ impl<'gc> TDisplayObject<'gc> for Avm2Button<'gc> {
         point: (Twips, Twips),
         options: HitTestOptions,
     ) -> bool {
-        if !options.contains(HitTestOptions::SKIP_INVISIBLE) || self.visible() {
+        if (!options.contains(HitTestOptions::SKIP_INVISIBLE) || self.visible())
+            && self.hit_test_masker(context, point)
+        {
             let state = self.0.read().state;
             if let Some(child) = self.get_state_child(state.into()) {
                 // hit_area is not actually a child, so transform point into local space before passing it down.
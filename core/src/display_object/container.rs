@@ -18,6 +18,9 @@ use std::ops::RangeBounds;
 
 /// Dispatch the `removedFromStage` event on a child and all of it's
 /// grandchildren, recursively.
+///
+/// Like `addedToStage`, this is dispatched to the child before any of its
+/// descendants, which matches Flash Player.
 pub fn dispatch_removed_from_stage_event<'gc>(
     child: DisplayObject<'gc>,
     context: &mut UpdateContext<'_, 'gc, '_>,
@@ -108,11 +108,11 @@ impl<'gc> TDisplayObject<'gc> for MorphShape<'gc> {
 
     fn hit_test_shape(
         &self,
-        _context: &mut UpdateContext<'_, 'gc, '_>,
+        context: &mut UpdateContext<'_, 'gc, '_>,
         point: (Twips, Twips),
         _options: HitTestOptions,
     ) -> bool {
-        if self.world_bounds().contains(point) {
+        if self.world_bounds().contains(point) && self.hit_test_masker(context, point) {
             if let Some(frame) = self.0.read().static_data.frames.borrow().get(&self.ratio()) {
                 let local_matrix = self.global_to_local_matrix();
                 let point = local_matrix * point;
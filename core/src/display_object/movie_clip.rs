@@ -1251,6 +1251,16 @@ impl<'gc> MovieClip<'gc> {
                     if let Some(clip_depth) = place_object.clip_depth {
                         child.set_clip_depth(context.gc_context, clip_depth.into());
                     }
+                    if cfg!(feature = "timeline_debug") {
+                        log::debug!(
+                            "[{}]: placed character {} as {} at depth {} (clip depth {})",
+                            self.name(),
+                            id,
+                            child.name(),
+                            child.depth(),
+                            child.clip_depth()
+                        );
+                    }
                     // Clip events only apply to movie clips.
                     if let (Some(clip_actions), Some(clip)) =
                         (&place_object.clip_actions, child.as_movie_clip())
@@ -2238,13 +2248,7 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
             return false;
         }
 
-        if self.world_bounds().contains(point) {
-            if let Some(masker) = self.masker() {
-                if !masker.hit_test_shape(context, point, HitTestOptions::SKIP_INVISIBLE) {
-                    return false;
-                }
-            }
-
+        if self.world_bounds().contains(point) && self.hit_test_masker(context, point) {
             let mut clip_depth = 0;
 
             for child in self.iter_render_list() {
@@ -157,7 +157,7 @@ impl<'gc> TDisplayObject<'gc> for Text<'gc> {
         mut point: (Twips, Twips),
         _options: HitTestOptions,
     ) -> bool {
-        if self.world_bounds().contains(point) {
+        if self.world_bounds().contains(point) && self.hit_test_masker(context, point) {
             // Texts using the "Advanced text rendering" always hit test using their bounding box.
             if self.0.read().render_settings.is_advanced() {
                 return true;
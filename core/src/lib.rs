@@ -46,6 +46,9 @@ pub mod external;
 pub use context_menu::ContextMenuItem;
 pub use events::PlayerEvent;
 pub use indexmap;
+#[cfg(any(test, feature = "deterministic"))]
+pub use locale::{set_current_date_time_override, set_timezone_override};
+pub use locale::{DateOrder, Locale};
 pub use player::{Player, PlayerBuilder};
 pub use ruffle_render::backend::ViewportDimensions;
 pub use swf;
@@ -1,22 +1,518 @@
-use chrono::{DateTime, FixedOffset, Local, Offset, TimeZone, Utc};
+use chrono::{
+    DateTime, Duration, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, Offset,
+    TimeZone, Utc,
+};
+#[cfg(feature = "timezones")]
+use chrono_tz::Tz;
+use std::cell::Cell;
+use std::fmt;
 
 // For tests, we emulate being in Nepal with a local time of 2001-02-03 at 04:05:06.
 // Nepal has a timezone offset of +5:45, and has never used DST.
 // This makes it an ideal candidate for fixed tests.
 const MOCK_TIME: bool = cfg!(any(test, feature = "deterministic"));
 
+thread_local! {
+    // Only consulted when `MOCK_TIME` is set, so that tests can pick other zones and times.
+    static TIMEZONE_OVERRIDE: Cell<Option<LocalTimezone>> = Cell::new(None);
+    static DATE_TIME_OVERRIDE: Cell<Option<DateTime<Utc>>> = Cell::new(None);
+    #[cfg(feature = "timezones")]
+    static HOST_TIMEZONE: Cell<Option<Option<Tz>>> = Cell::new(None);
+}
+
+/// The timezone that local dates are displayed in.
+///
+/// When the host's zone can be identified in the IANA tz database, we use that
+/// so that DST and historical offset changes are respected. Otherwise we fall
+/// back to whatever offset the host is currently using.
+///
+/// The tz database is only available with the `timezones` feature, as it adds
+/// considerably to the size of the web build.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LocalTimezone {
+    Fixed(FixedOffset),
+    #[cfg(feature = "timezones")]
+    Named(Tz),
+}
+
+impl From<FixedOffset> for LocalTimezone {
+    fn from(offset: FixedOffset) -> Self {
+        Self::Fixed(offset)
+    }
+}
+
+#[cfg(feature = "timezones")]
+impl From<Tz> for LocalTimezone {
+    fn from(tz: Tz) -> Self {
+        Self::Named(tz)
+    }
+}
+
+/// The offset of a `LocalTimezone` at a particular instant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LocalOffset {
+    timezone: LocalTimezone,
+    offset: FixedOffset,
+}
+
+impl LocalOffset {
+    /// The number of seconds to add to a local time to get UTC.
+    pub fn utc_minus_local(&self) -> i32 {
+        self.offset.utc_minus_local()
+    }
+}
+
+impl Offset for LocalOffset {
+    fn fix(&self) -> FixedOffset {
+        self.offset
+    }
+}
+
+impl fmt::Display for LocalOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.offset, f)
+    }
+}
+
+impl LocalTimezone {
+    fn with_offset(self, offset: FixedOffset) -> LocalOffset {
+        LocalOffset {
+            timezone: self,
+            offset,
+        }
+    }
+}
+
+impl TimeZone for LocalTimezone {
+    type Offset = LocalOffset;
+
+    fn from_offset(offset: &LocalOffset) -> Self {
+        offset.timezone
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<LocalOffset> {
+        match self {
+            Self::Fixed(offset) => LocalResult::Single(self.with_offset(*offset)),
+            #[cfg(feature = "timezones")]
+            Self::Named(tz) => tz
+                .offset_from_local_date(local)
+                .map(|offset| self.with_offset(offset.fix())),
+        }
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<LocalOffset> {
+        match self {
+            Self::Fixed(offset) => LocalResult::Single(self.with_offset(*offset)),
+            #[cfg(feature = "timezones")]
+            Self::Named(tz) => tz
+                .offset_from_local_datetime(local)
+                .map(|offset| self.with_offset(offset.fix())),
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> LocalOffset {
+        match self {
+            Self::Fixed(offset) => self.with_offset(*offset),
+            #[cfg(feature = "timezones")]
+            Self::Named(tz) => self.with_offset(tz.offset_from_utc_date(utc).fix()),
+        }
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> LocalOffset {
+        match self {
+            Self::Fixed(offset) => self.with_offset(*offset),
+            #[cfg(feature = "timezones")]
+            Self::Named(tz) => self.with_offset(tz.offset_from_utc_datetime(utc).fix()),
+        }
+    }
+}
+
+/// Converts a local time in `timezone` into UTC, following ECMA-262's `UTC(t)`.
+///
+/// A time that happens twice because the clocks went back resolves to its first
+/// occurrence. A time that was skipped because the clocks went forward uses the
+/// offset from before the change, which moves it forward by the length of the gap.
+pub fn local_to_utc<T: TimeZone>(timezone: &T, local: &NaiveDateTime) -> Option<DateTime<Utc>> {
+    let offset = match timezone.offset_from_local_datetime(local) {
+        LocalResult::Single(offset) | LocalResult::Ambiguous(offset, _) => offset.fix(),
+        LocalResult::None => {
+            // Offset changes are always more than a day apart.
+            let before = local.checked_sub_signed(Duration::days(1))?;
+            timezone
+                .offset_from_local_datetime(&before)
+                .earliest()?
+                .fix()
+        }
+    };
+    let utc = local.checked_sub_signed(Duration::seconds(offset.local_minus_utc().into()))?;
+    Some(DateTime::from_utc(utc, Utc))
+}
+
+/// Force `get_timezone` to return the given timezone on this thread,
+/// instead of the mock timezone. Pass `None` to remove the override.
+#[cfg(any(test, feature = "deterministic"))]
+pub fn set_timezone_override(timezone: Option<LocalTimezone>) {
+    TIMEZONE_OVERRIDE.with(|tz| tz.set(timezone));
+}
+
+/// Force `get_current_date_time` to return the given time on this thread,
+/// instead of the mock time. Pass `None` to remove the override.
+#[cfg(any(test, feature = "deterministic"))]
+pub fn set_current_date_time_override(date_time: Option<DateTime<Utc>>) {
+    DATE_TIME_OVERRIDE.with(|dt| dt.set(date_time));
+}
+
 pub fn get_current_date_time() -> DateTime<Utc> {
     if MOCK_TIME {
-        get_timezone().ymd(2001, 2, 3).and_hms(4, 5, 6).into()
+        DATE_TIME_OVERRIDE.with(Cell::get).unwrap_or_else(|| {
+            get_timezone()
+                .ymd(2001, 2, 3)
+                .and_hms(4, 5, 6)
+                .with_timezone(&Utc)
+        })
     } else {
         Utc::now()
     }
 }
 
-pub fn get_timezone() -> FixedOffset {
+pub fn get_timezone() -> LocalTimezone {
     if MOCK_TIME {
-        FixedOffset::east(20700)
+        TIMEZONE_OVERRIDE
+            .with(Cell::get)
+            .unwrap_or_else(|| FixedOffset::east(20700).into())
     } else {
-        Local::now().offset().fix()
+        host_timezone().unwrap_or_else(|| Local::now().offset().fix().into())
+    }
+}
+
+/// Look up the host's configured zone (e.g. `Europe/London`) in the tz database.
+///
+/// Every `Date` setter asks for the local timezone, so the lookup (which hits the
+/// filesystem and then searches the tz database by name) is only done once per thread.
+#[cfg(feature = "timezones")]
+fn host_timezone() -> Option<LocalTimezone> {
+    let timezone = HOST_TIMEZONE.with(|cached| {
+        if let Some(timezone) = cached.get() {
+            return timezone;
+        }
+        let timezone = lookup_host_timezone();
+        cached.set(Some(timezone));
+        timezone
+    });
+    timezone.map(LocalTimezone::Named)
+}
+
+/// Without the tz database, only the offset the host is currently using is known.
+#[cfg(not(feature = "timezones"))]
+fn host_timezone() -> Option<LocalTimezone> {
+    None
+}
+
+#[cfg(feature = "timezones")]
+fn lookup_host_timezone() -> Option<Tz> {
+    iana_time_zone::get_timezone().ok()?.parse().ok()
+}
+
+/// The order in which a locale writes the parts of a date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateOrder {
+    /// `Sat Feb 3 2001`, as used in the US.
+    MonthDayYear,
+
+    /// `Sat 3 Feb 2001`, as used in most of Europe.
+    DayMonthYear,
+
+    /// `Sat 2001 Feb 3`, as used in East Asia.
+    YearMonthDay,
+}
+
+/// How the host's locale writes dates and times.
+///
+/// This drives the `toLocale*String` methods of `Date`, whose output varies with the OS
+/// locale in Flash Player.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Locale {
+    pub date_order: DateOrder,
+
+    /// Whether times use a 12-hour clock with an AM/PM marker.
+    pub twelve_hour_clock: bool,
+}
+
+impl Locale {
+    /// The US English conventions, such as `Sat Feb 3 2001 04:05:06 AM`.
+    pub const US: Self = Self {
+        date_order: DateOrder::MonthDayYear,
+        twelve_hour_clock: true,
+    };
+
+    /// Typical European conventions, such as `Sat 3 Feb 2001 04:05:06`.
+    pub const EUROPEAN: Self = Self {
+        date_order: DateOrder::DayMonthYear,
+        twelve_hour_clock: false,
+    };
+
+    /// Picks the conventions for a locale name, such as `en-US`, `de-DE` or `en_GB.UTF-8`.
+    ///
+    /// Only the broad families of formats are distinguished. Anything that isn't recognised
+    /// uses the European day-month-year order with a 24-hour clock.
+    pub fn from_language_tag(tag: &str) -> Self {
+        let tag = tag
+            .split(|c| c == '.' || c == '@')
+            .next()
+            .unwrap_or_default();
+        let mut parts = tag.split(|c| c == '-' || c == '_');
+        let language = parts.next().unwrap_or_default().to_ascii_lowercase();
+        let region = parts
+            .find(|part| part.len() == 2)
+            .map(|region| region.to_ascii_uppercase());
+
+        if matches!(language.as_str(), "" | "c" | "posix") {
+            return Self::default();
+        }
+
+        let date_order = match (language.as_str(), region.as_deref()) {
+            ("en", None | Some("US" | "PH")) => DateOrder::MonthDayYear,
+            ("ja" | "ko" | "zh" | "hu", _) => DateOrder::YearMonthDay,
+            _ => DateOrder::DayMonthYear,
+        };
+        let twelve_hour_clock = match region.as_deref() {
+            Some(region) => matches!(
+                region,
+                "US" | "CA" | "AU" | "NZ" | "IN" | "PH" | "PK" | "EG"
+            ),
+            None => language == "en",
+        };
+
+        Self {
+            date_order,
+            twelve_hour_clock,
+        }
+    }
+
+    /// The `chrono` format used to write a date.
+    pub fn date_format(&self) -> &'static str {
+        match self.date_order {
+            DateOrder::MonthDayYear => "%a %b %-d %-Y",
+            DateOrder::DayMonthYear => "%a %-d %b %-Y",
+            DateOrder::YearMonthDay => "%a %-Y %b %-d",
+        }
+    }
+
+    /// The `chrono` format used to write a time of day.
+    pub fn time_format(&self) -> &'static str {
+        if self.twelve_hour_clock {
+            "%I:%M:%S %p"
+        } else {
+            "%T"
+        }
+    }
+
+    /// The `chrono` format used to write both a date and a time of day.
+    pub fn date_time_format(&self) -> String {
+        format!("{} {}", self.date_format(), self.time_format())
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::US
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "timezones")]
+    use chrono::Timelike;
+    #[cfg(feature = "timezones")]
+    use std::time::Instant;
+
+    #[cfg(feature = "timezones")]
+    fn local(timezone: impl Into<LocalTimezone>, utc: DateTime<Utc>) -> DateTime<LocalTimezone> {
+        utc.with_timezone(&timezone.into())
+    }
+
+    #[test]
+    fn overrides_take_precedence() {
+        let tz = FixedOffset::west(3600 * 5);
+        set_timezone_override(Some(tz.into()));
+        assert_eq!(get_timezone(), LocalTimezone::Fixed(tz));
+
+        let now = Utc.ymd(2020, 6, 15).and_hms(12, 0, 0);
+        set_current_date_time_override(Some(now));
+        assert_eq!(get_current_date_time(), now);
+
+        set_timezone_override(None);
+        set_current_date_time_override(None);
+        assert_eq!(
+            get_timezone(),
+            LocalTimezone::Fixed(FixedOffset::east(20700))
+        );
+        assert_eq!(
+            get_current_date_time(),
+            FixedOffset::east(20700).ymd(2001, 2, 3).and_hms(4, 5, 6)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn local_to_utc_around_dst_changes() {
+        let tz = chrono_tz::America::New_York;
+
+        // Midday after the clocks went forward is in EDT.
+        let local = NaiveDate::from_ymd(2021, 3, 20).and_hms(12, 0, 0);
+        assert_eq!(
+            local_to_utc(&tz, &local),
+            Some(Utc.ymd(2021, 3, 20).and_hms(16, 0, 0))
+        );
+
+        // 02:30 on March 14th was skipped, so it becomes 03:30 EDT.
+        let local = NaiveDate::from_ymd(2021, 3, 14).and_hms(2, 30, 0);
+        assert_eq!(
+            local_to_utc(&tz, &local),
+            Some(Utc.ymd(2021, 3, 14).and_hms(7, 30, 0))
+        );
+
+        // 01:30 on November 7th happened twice, and resolves to the first time (in EDT).
+        let local = NaiveDate::from_ymd(2021, 11, 7).and_hms(1, 30, 0);
+        assert_eq!(
+            local_to_utc(&tz, &local),
+            Some(Utc.ymd(2021, 11, 7).and_hms(5, 30, 0))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn new_york_dst_boundary() {
+        let tz = chrono_tz::America::New_York;
+
+        // DST started at 2021-03-14 02:00 local time (07:00 UTC).
+        let before = local(tz, Utc.ymd(2021, 3, 14).and_hms(6, 59, 0));
+        assert_eq!(before.offset().utc_minus_local(), 5 * 3600);
+        assert_eq!(before.hour(), 1);
+        assert_eq!(before.minute(), 59);
+
+        let after = local(tz, Utc.ymd(2021, 3, 14).and_hms(7, 0, 0));
+        assert_eq!(after.offset().utc_minus_local(), 4 * 3600);
+        assert_eq!(after.hour(), 3);
+
+        // Local times constructed from the zone pick up the offset in effect on that day.
+        let summer = LocalTimezone::from(tz).ymd(2021, 7, 1).and_hms(12, 0, 0);
+        assert_eq!(summer.offset().utc_minus_local(), 4 * 3600);
+        assert_eq!(summer.timezone(), LocalTimezone::Named(tz));
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn kolkata_half_hour_offset() {
+        let date = local(
+            chrono_tz::Asia::Kolkata,
+            Utc.ymd(2021, 1, 1).and_hms(0, 0, 0),
+        );
+        assert_eq!(date.offset().utc_minus_local(), -(5 * 3600 + 30 * 60));
+        assert_eq!(date.hour(), 5);
+        assert_eq!(date.minute(), 30);
+        assert_eq!(date.format("%z").to_string(), "+0530");
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn gmt_offsets() {
+        // Flash formats offsets as `GMT+HHMM`, without a colon.
+        let winter = Utc.ymd(2021, 1, 15).and_hms(12, 0, 0);
+        let summer = Utc.ymd(2021, 7, 15).and_hms(12, 0, 0);
+
+        let new_york = LocalTimezone::from(chrono_tz::America::New_York);
+        assert_eq!(
+            winter.with_timezone(&new_york).format("GMT%z").to_string(),
+            "GMT-0500"
+        );
+        assert_eq!(
+            summer.with_timezone(&new_york).format("GMT%z").to_string(),
+            "GMT-0400"
+        );
+
+        let tokyo = LocalTimezone::from(chrono_tz::Asia::Tokyo);
+        assert_eq!(
+            winter.with_timezone(&tokyo).format("GMT%z").to_string(),
+            "GMT+0900"
+        );
+
+        let nepal = LocalTimezone::from(FixedOffset::east(20700));
+        assert_eq!(
+            winter.with_timezone(&nepal).format("GMT%z").to_string(),
+            "GMT+0545"
+        );
+    }
+
+    #[test]
+    fn us_and_european_locale_formats() {
+        let date = LocalTimezone::from(FixedOffset::east(0))
+            .ymd(2001, 2, 3)
+            .and_hms(16, 5, 6);
+
+        let us = Locale::from_language_tag("en-US");
+        assert_eq!(us, Locale::US);
+        assert_eq!(
+            date.format(&us.date_time_format()).to_string(),
+            "Sat Feb 3 2001 04:05:06 PM"
+        );
+        assert_eq!(date.format(us.time_format()).to_string(), "04:05:06 PM");
+
+        let german = Locale::from_language_tag("de_DE.UTF-8");
+        assert_eq!(german, Locale::EUROPEAN);
+        assert_eq!(
+            date.format(&german.date_time_format()).to_string(),
+            "Sat 3 Feb 2001 16:05:06"
+        );
+        assert_eq!(date.format(german.time_format()).to_string(), "16:05:06");
+
+        assert_eq!(Locale::from_language_tag("en-GB"), Locale::EUROPEAN);
+        assert_eq!(Locale::from_language_tag("C"), Locale::default());
+
+        let japanese = Locale::from_language_tag("ja-JP");
+        assert_eq!(japanese.date_order, DateOrder::YearMonthDay);
+        assert_eq!(
+            date.format(&japanese.date_time_format()).to_string(),
+            "Sat 2001 Feb 3 16:05:06"
+        );
+    }
+
+    /// Times the host timezone lookup behind every `Date` setter, with and without
+    /// the per-thread cache.
+    ///
+    /// Run with `cargo test --release -p ruffle_core host_timezone_lookup -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    #[cfg(feature = "timezones")]
+    fn host_timezone_lookup() {
+        const ITERATIONS: u32 = 1000;
+        let expected = lookup_host_timezone();
+
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            assert_eq!(lookup_host_timezone(), expected);
+        }
+        let uncached = start.elapsed();
+
+        let expected = expected.map(LocalTimezone::from);
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            assert_eq!(host_timezone(), expected);
+        }
+        let cached = start.elapsed();
+
+        println!(
+            "{} uncached lookups: {:?} ({:?} each)",
+            ITERATIONS,
+            uncached,
+            uncached / ITERATIONS
+        );
+        println!(
+            "{} cached lookups: {:?} ({:?} each)",
+            ITERATIONS,
+            cached,
+            cached / ITERATIONS
+        );
     }
 }
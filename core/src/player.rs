@@ -31,7 +31,7 @@ use crate::font::Font;
 use crate::frame_lifecycle::{run_all_phases_avm1, run_all_phases_avm2, FramePhase};
 use crate::library::Library;
 use crate::loader::LoadManager;
-use crate::locale::get_current_date_time;
+use crate::locale::{get_current_date_time, Locale};
 use crate::prelude::*;
 use crate::string::AvmString;
 use crate::tag_utils::SwfMovie;
@@ -217,6 +217,9 @@ pub struct Player {
 
     system: SystemProperties,
 
+    /// How the host's locale writes dates and times.
+    locale: Locale,
+
     /// The current instance ID. Used to generate default `instanceN` names.
     instance_counter: i32,
 
@@ -1496,6 +1499,7 @@ impl Player {
                 player: self.self_reference.clone(),
                 load_manager,
                 system: &mut self.system,
+                locale: self.locale,
                 instance_counter: &mut self.instance_counter,
                 storage: self.storage.deref_mut(),
                 log: self.log.deref_mut(),
@@ -1651,6 +1655,11 @@ impl Player {
     pub fn set_max_execution_duration(&mut self, max_execution_duration: Duration) {
         self.max_execution_duration = max_execution_duration
     }
+
+    /// Sets the locale used to format dates and times.
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+    }
 }
 
 /// Player factory, which can be used to configure the aspects of a Ruffle player.
@@ -1670,6 +1679,7 @@ pub struct PlayerBuilder {
     autoplay: bool,
     fullscreen: bool,
     letterbox: Letterbox,
+    locale: Locale,
     max_execution_duration: Duration,
     viewport_width: u32,
     viewport_height: u32,
@@ -1699,6 +1709,7 @@ impl PlayerBuilder {
             fullscreen: false,
             // Disable script timeout in debug builds by default.
             letterbox: Letterbox::Fullscreen,
+            locale: Locale::default(),
             max_execution_duration: Duration::from_secs(if cfg!(debug_assertions) {
                 u64::MAX
             } else {
@@ -1788,6 +1799,13 @@ impl PlayerBuilder {
         self
     }
 
+    /// Sets the locale used to format dates and times.
+    #[inline]
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
     /// Sets the maximum execution time of ActionScript code.
     #[inline]
     pub fn with_max_execution_duration(mut self, duration: Duration) -> Self {
@@ -1889,6 +1907,7 @@ impl PlayerBuilder {
                 // Misc. state
                 rng: SmallRng::seed_from_u64(get_current_date_time().timestamp_millis() as u64),
                 system: SystemProperties::default(),
+                locale: self.locale,
                 transform_stack: TransformStack::new(),
                 instance_counter: 0,
                 player_version: NEWEST_PLAYER_VERSION,
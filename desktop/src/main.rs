@@ -20,8 +20,8 @@ use clap::Parser;
 use isahc::{config::RedirectPolicy, prelude::*, HttpClient};
 use rfd::FileDialog;
 use ruffle_core::{
-    config::Letterbox, events::KeyCode, tag_utils::SwfMovie, Player, PlayerBuilder, PlayerEvent,
-    StageDisplayState, ViewportDimensions,
+    config::Letterbox, events::KeyCode, tag_utils::SwfMovie, Locale, Player, PlayerBuilder,
+    PlayerEvent, StageDisplayState, ViewportDimensions,
 };
 use ruffle_render_wgpu::clap::{GraphicsBackend, PowerPreference};
 use ruffle_render_wgpu::WgpuRenderBackend;
@@ -136,6 +136,15 @@ fn parse_parameters(opt: &Opt) -> impl '_ + Iterator<Item = (String, String)> {
     })
 }
 
+/// Guesses the host's locale from the POSIX locale environment variables.
+fn host_locale() -> Locale {
+    ["LC_ALL", "LC_TIME", "LANG"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|value| !value.is_empty()))
+        .map(|tag| Locale::from_language_tag(&tag))
+        .unwrap_or_default()
+}
+
 fn pick_file() -> Option<PathBuf> {
     FileDialog::new()
         .add_filter(".swf", &["swf"])
@@ -257,6 +266,7 @@ impl App {
             .with_software_video()
             .with_autoplay(true)
             .with_letterbox(Letterbox::On)
+            .with_locale(host_locale())
             .with_warn_on_unsupported_content(!opt.dont_warn_on_unsupported_content)
             .with_fullscreen(opt.fullscreen);
 
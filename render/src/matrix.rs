@@ -265,6 +265,30 @@ mod tests {
         (Matrix::default(), Matrix::default())
     );
 
+    // A matrix with a determinant of 0 has no inverse, so every point ends up at the origin.
+    #[test]
+    fn invert_singular_matrix() {
+        let mut matrix = Matrix {
+            a: 0.0,
+            c: 0.0,
+            tx: Twips::from_pixels(100.0),
+            b: 0.0,
+            d: 1.0,
+            ty: Twips::from_pixels(50.0),
+        };
+        matrix.invert();
+        assert_eq!(matrix.tx, Twips::ZERO);
+        assert_eq!(matrix.ty, Twips::ZERO);
+        assert_eq!(
+            matrix * (Twips::from_pixels(150.0), Twips::from_pixels(70.0)),
+            (Twips::ZERO, Twips::ZERO)
+        );
+        assert_eq!(
+            matrix * (Twips::ZERO, Twips::ZERO),
+            (Twips::ZERO, Twips::ZERO)
+        );
+    }
+
     // Standard test cases; there's nothing special about these matrices.
     test_invert!(
         invert_matrices,
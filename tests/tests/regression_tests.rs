@@ -3,6 +3,7 @@
 //! Trace output can be compared with correct output from the official Flash Player.
 
 use approx::assert_relative_eq;
+use chrono::{TimeZone, Utc};
 use regex::Regex;
 use ruffle_core::backend::{
     log::LogBackend,
@@ -14,7 +15,10 @@ use ruffle_core::events::MouseButton as RuffleMouseButton;
 use ruffle_core::external::Value as ExternalValue;
 use ruffle_core::external::{ExternalInterfaceMethod, ExternalInterfaceProvider};
 use ruffle_core::tag_utils::SwfMovie;
-use ruffle_core::{Player, PlayerBuilder, PlayerEvent, ViewportDimensions};
+use ruffle_core::{
+    set_current_date_time_override, set_timezone_override, Locale, Player, PlayerBuilder,
+    PlayerEvent, ViewportDimensions,
+};
 use ruffle_input_format::{AutomatedEvent, InputInjector, MouseButton as InputMouseButton};
 
 #[cfg(feature = "imgtests")]
@@ -199,6 +203,7 @@ swf_tests! {
     (as3_bytearray_readobject_amf0, "avm2/bytearray_readobject_amf0", 1),
     (as3_bytearray_readobject_amf3, "avm2/bytearray_readobject_amf3", 1),
     (as3_bytearray, "avm2/bytearray", 1),
+    (as3_bytearray_readobject_date, "avm2/bytearray_readobject_date", 1),
     (as3_class_call, "avm2/class_call", 1),
     (as3_class_cast_call, "avm2/class_cast_call", 1),
     (as3_class_enumeration, "avm2/class_enumeration", 1),
@@ -211,6 +216,9 @@ swf_tests! {
     (as3_closures, "avm2/closures", 1),
     (as3_coerce_property, "avm2/coerce_property", 1),
     (as3_coerce_string, "avm2/coerce_string", 1),
+    #[ignore] (as3_colortransform_color, "avm2/colortransform_color", 1), //Ignored because the expected output was written by hand, not captured from Flash Player
+    #[ignore] (as3_colortransform_concat, "avm2/colortransform_concat", 1), //Ignored because the expected output was written by hand, not captured from Flash Player
+    (as3_colortransform_constructor_clamp, "avm2/colortransform_constructor_clamp", 1),
     (as3_constructor_call, "avm2/constructor_call", 1),
     (as3_control_flow_bool, "avm2/control_flow_bool", 1),
     (as3_control_flow_stricteq, "avm2/control_flow_stricteq", 1),
@@ -218,8 +226,24 @@ swf_tests! {
     (as3_convert_integer, "avm2/convert_integer", 1),
     (as3_convert_number, "avm2/convert_number", 1),
     (as3_convert_uinteger, "avm2/convert_uinteger", 1),
+    (as3_date_copy_constructor, "avm2/date_copy_constructor", 1),
+    (as3_date_getter_ranges, "avm2/date_getter_ranges", 1),
+    (as3_date_hint_coercion, "avm2/date_hint_coercion", 1),
+    (as3_date_local_fields_cache, "avm2/date_local_fields_cache", 1),
     (as3_date_parse, "avm2/date_parse", 1),
+    (as3_date_parse_iso, "avm2/date_parse_iso", 1),
     (as3_date, "avm2/date", 1),
+    (as3_date_sethours_carry, "avm2/date_sethours_carry", 1),
+    (as3_date_sethours_undefined, "avm2/date_sethours_undefined", 1),
+    (as3_date_setmonth_optional_day, "avm2/date_setmonth_optional_day", 1),
+    (as3_date_setmonth_overflow, "avm2/date_setmonth_overflow", 1),
+    (as3_date_setters_nonfinite, "avm2/date_setters_nonfinite", 1),
+    #[ignore] (as3_date_settime_extremes, "avm2/date_settime_extremes", 1), //Ignored because chrono can't represent dates as far out as Flash's maximum timestamp
+    (as3_date_settime_fractional, "avm2/date_settime_fractional", 1),
+    (as3_date_time_setter_styles, "avm2/date_time_setter_styles", 1),
+    (as3_date_tolocalestring, "avm2/date_tolocalestring", 1),
+    (as3_date_tostring_names, "avm2/date_tostring_names", 1),
+    (as3_date_year_overflow, "avm2/date_year_overflow", 1),
     (as3_declocal_i, "avm2/declocal_i", 1),
     (as3_declocal, "avm2/declocal", 1),
     (as3_decrement_i, "avm2/decrement_i", 1),
@@ -231,16 +255,52 @@ swf_tests! {
     (as3_dictionary_hasownproperty, "avm2/dictionary_hasownproperty", 1),
     (as3_dictionary_in, "avm2/dictionary_in", 1),
     (as3_dictionary_namespaces, "avm2/dictionary_namespaces", 1),
+    (as3_displayobject_accessibilityproperties, "avm2/displayobject_accessibilityproperties", 1),
     (as3_displayobject_alpha, "avm2/displayobject_alpha", 1),
+    (as3_displayobject_alpha_colortransform, "avm2/displayobject_alpha_colortransform", 1),
+    (as3_displayobject_alpha_from_colortransform, "avm2/displayobject_alpha_from_colortransform", 1),
     (as3_displayobject_blendmode, "avm2/displayobject_blendmode", 1, img = true),
+    (as3_displayobject_blendmode_roundtrip, "avm2/displayobject_blendmode_roundtrip", 1),
+    (as3_displayobject_bounds_cache, "avm2/displayobject_bounds_cache", 1),
+    (as3_displayobject_filters, "avm2/displayobject_filters", 1),
+    (as3_displayobject_filters_dropshadow, "avm2/displayobject_filters_dropshadow", 1),
+    (as3_displayobject_getbounds_empty, "avm2/displayobject_getbounds_empty", 1),
+    (as3_displayobject_getbounds_scrollrect, "avm2/displayobject_getbounds_scrollrect", 3),
+    (as3_displayobject_getbounds_self, "avm2/displayobject_getbounds_self", 1),
+    (as3_displayobject_getbounds_union, "avm2/displayobject_getbounds_union", 1),
     (as3_displayobject_hittestobject, "avm2/displayobject_hittestobject", 1),
     (as3_displayobject_hittestpoint, "avm2/displayobject_hittestpoint", 2),
+    (as3_displayobject_hittestpoint_invisible, "avm2/displayobject_hittestpoint_invisible", 1),
+    (as3_displayobject_hittestpoint_mask, "avm2/displayobject_hittestpoint_mask", 1),
+    (as3_displayobject_hittestpoint_nested, "avm2/displayobject_hittestpoint_nested", 1),
+    (as3_displayobject_lifecycle_event_order, "avm2/displayobject_lifecycle_event_order", 1),
+    (as3_displayobject_local3d, "avm2/displayobject_local3d", 1),
+    (as3_displayobject_metadata, "avm2/displayobject_metadata", 1),
+    (as3_displayobject_mousexy_rotated, "avm2/displayobject_mousexy_rotated", 4),
+    (as3_displayobject_mousexy_scrollrect, "avm2/displayobject_mousexy_scrollrect", 5),
     (as3_displayobject_name, "avm2/displayobject_name", 4),
+    (as3_displayobject_name_lookup, "avm2/displayobject_name_lookup", 1),
+    (as3_displayobject_name_timeline, "avm2/displayobject_name_timeline", 1),
     (as3_displayobject_parent, "avm2/displayobject_parent", 4),
     (as3_displayobject_root, "avm2/displayobject_root", 4),
+    (as3_displayobject_root_detached, "avm2/displayobject_root_detached", 1),
+    (as3_displayobject_rotation_quantization, "avm2/displayobject_rotation_quantization", 1),
+    (as3_displayobject_scale_nonfinite, "avm2/displayobject_scale_nonfinite", 1),
+    (as3_displayobject_scale_rotation_reads, "avm2/displayobject_scale_rotation_reads", 1),
     (as3_displayobject_scrollrect, "avm2/displayobject_scrollrect", 100, img = true),
+    (as3_displayobject_stage_detached, "avm2/displayobject_stage_detached", 1),
+    (as3_displayobject_timeline_depths, "avm2/displayobject_timeline_depths", 1),
+    (as3_displayobject_transform_degenerate, "avm2/displayobject_transform_degenerate", 1),
+    (as3_displayobject_transform_invalidation, "avm2/displayobject_transform_invalidation", 3),
+    (as3_displayobject_transform_live, "avm2/displayobject_transform_live", 1),
+    (as3_displayobject_transform_matrix_only, "avm2/displayobject_transform_matrix_only", 1),
+    (as3_displayobject_transform_null, "avm2/displayobject_transform_null", 1),
+    (as3_displayobject_transform_partial, "avm2/displayobject_transform_partial", 1),
+    (as3_displayobject_transform_tint, "avm2/displayobject_transform_tint", 1),
     (as3_displayobject_visible, "avm2/displayobject_visible", 4),
+    (as3_displayobject_width_empty, "avm2/displayobject_width_empty", 1),
     (as3_displayobject_x, "avm2/displayobject_x", 1),
+    (as3_displayobject_x_y_twips, "avm2/displayobject_x_y_twips", 1),
     (as3_displayobject_y, "avm2/displayobject_y", 1),
     (as3_displayobjectcontainer_addchild_timelinepull0, "avm2/displayobjectcontainer_addchild_timelinepull0", 7),
     (as3_displayobjectcontainer_addchild_timelinepull1, "avm2/displayobjectcontainer_addchild_timelinepull1", 7),
@@ -294,6 +354,8 @@ swf_tests! {
     (as3_eventdispatcher_tostring, "avm2/eventdispatcher_tostring", 1),
     (as3_eventdispatcher_willtrigger, "avm2/eventdispatcher_willtrigger", 1),
     (as3_falsiness, "avm2/falsiness", 1),
+    (as3_filter_clamping, "avm2/filter_clamping", 1),
+    (as3_filter_defaults, "avm2/filter_defaults", 1),
     (as3_font_embedded, "avm2/font_embedded", 1),
     (as3_font_hasglyphs, "avm2/font_hasglyphs", 1),
     (as3_framelabel_constr, "avm2/framelabel_constr", 5),
@@ -321,6 +383,7 @@ swf_tests! {
     (as3_has_own_property, "avm2/has_own_property", 1),
     (as3_hasownproperty_namespaces, "avm2/hasownproperty_namespaces", 1),
     (as3_hello_world, "avm2/hello_world", 1),
+    (as3_hittestobject_rotated, "avm2/hittestobject_rotated", 1),
     (as3_if_eq, "avm2/if_eq", 1),
     (as3_if_gt, "avm2/if_gt", 1),
     (as3_if_gte, "avm2/if_gte", 1),
@@ -357,10 +420,18 @@ swf_tests! {
     (as3_lessthan, "avm2/lessthan", 1),
     (as3_loader_events, "avm2/loader_events", 3, img = true),
     (as3_loaderinfo_events, "avm2/loaderinfo_events", 2),
+    (as3_loaderinfo_loaded_content, "avm2/loaderinfo_loaded_content", 3),
+    (as3_loaderinfo_main_root, "avm2/loaderinfo_main_root", 1),
     (as3_loaderinfo_properties, "avm2/loaderinfo_properties", 2),
     (as3_loaderinfo_root, "avm2/loaderinfo_root", 1),
     (as3_loaderinfo_quine, "avm2/loaderinfo_quine", 2),
     (as3_lshift, "avm2/lshift", 1),
+    (as3_matrix3d_decompose_recompose, "avm2/matrix3d_decompose_recompose", 1),
+    (as3_matrix3d_rotation, "avm2/matrix3d_rotation", 1),
+    (as3_matrix3d_translation, "avm2/matrix3d_translation", 1),
+    #[ignore] (as3_matrix_clone_tostring, "avm2/matrix_clone_tostring", 1), //Ignored because the expected output was written by hand, not captured from Flash Player
+    (as3_matrix_copy_row_column, "avm2/matrix_copy_row_column", 1),
+    #[ignore] (as3_matrix_transformpoint, "avm2/matrix_transformpoint", 1), //Ignored because the expected output was written by hand, not captured from Flash Player
     (as3_modulo, "avm2/modulo", 1),
     (as3_mouseevent_constr, "avm2/mouseevent_constr", 1),
     (as3_mouseevent_stagexy, "avm2/mouseevent_stagexy", 1),
@@ -429,9 +500,14 @@ swf_tests! {
     (as3_op_escxelem, "avm2/op_escxelem", 1),
     (as3_op_lookupswitch, "avm2/op_lookupswitch", 1),
     (as3_parse_int, "avm2/parse_int", 1),
+    (as3_perspective_projection, "avm2/perspective_projection", 1),
     (as3_place_object_replace_2, "avm2/place_object_replace_2", 3),
     (as3_place_object_replace, "avm2/place_object_replace", 2),
     (as3_point, "avm2/point", 1),
+    #[ignore] (as3_point_distance, "avm2/point_distance", 1), //Ignored because the expected output was written by hand, not captured from Flash Player
+    #[ignore] (as3_point_interpolate_polar, "avm2/point_interpolate_polar", 1), //Ignored because the expected output was written by hand, not captured from Flash Player
+    #[ignore] (as3_point_setto_copyfrom, "avm2/point_setto_copyfrom", 1), //Ignored because the expected output was written by hand, not captured from Flash Player
+    #[ignore] (as3_point_vector_math, "avm2/point_vector_math", 1), //Ignored because the expected output was written by hand, not captured from Flash Player
     (as3_property_is_enumerable, "avm2/property_is_enumerable", 1),
     (as3_propertyisenumerable_namespaces, "avm2/propertyisenumerable_namespaces", 1),
     (as3_proxy_callproperty, "avm2/proxy_callproperty", 1),
@@ -445,6 +521,10 @@ swf_tests! {
     (as3_qname_tostring, "avm2/qname_tostring", 1),
     (as3_qname_valueof, "avm2/qname_valueof", 1),
     (as3_rectangle, "avm2/rectangle", 1),
+    #[ignore] (as3_rectangle_contains, "avm2/rectangle_contains", 1), //Ignored because the expected output was written by hand, not captured from Flash Player
+    #[ignore] (as3_rectangle_isempty_copyfrom, "avm2/rectangle_isempty_copyfrom", 1), //Ignored because the expected output was written by hand, not captured from Flash Player
+    #[ignore] (as3_rectangle_point_accessors, "avm2/rectangle_point_accessors", 1), //Ignored because the expected output was written by hand, not captured from Flash Player
+    #[ignore] (as3_rectangle_union_intersection, "avm2/rectangle_union_intersection", 1), //Ignored because the expected output was written by hand, not captured from Flash Player
     (as3_vector3d, "avm2/vector3d", 1),
     (as3_regexp_constr, "avm2/regexp_constr", 1),
     (as3_regexp_exec, "avm2/regexp_exec", 1),
@@ -500,6 +580,15 @@ swf_tests! {
     (as3_throw, "avm2/throw", 1),
     (as3_timeline_scripts, "avm2/timeline_scripts", 3),
     (as3_trace, "avm2/trace", 1),
+    (as3_transform_colortransform_fields, "avm2/transform_colortransform_fields", 1),
+    (as3_transform_colortransform_live, "avm2/transform_colortransform_live", 1),
+    (as3_transform_concatenatedcolortransform, "avm2/transform_concatenatedcolortransform", 1),
+    (as3_transform_concatenatedmatrix, "avm2/transform_concatenatedmatrix", 1),
+    (as3_transform_matrix3d_lifecycle, "avm2/transform_matrix3d_lifecycle", 1),
+    (as3_transform_matrix_nan, "avm2/transform_matrix_nan", 1),
+    (as3_transform_matrix_roundtrip, "avm2/transform_matrix_roundtrip", 1),
+    (as3_transform_matrix_twips, "avm2/transform_matrix_twips", 1),
+    (as3_transform_pixelbounds, "avm2/transform_pixelbounds", 1),
     (as3_truthiness, "avm2/truthiness", 1),
     (as3_typeof, "avm2/typeof", 1),
     (as3_uint_constr, "avm2/uint_constr", 1),
@@ -510,6 +599,7 @@ swf_tests! {
     (as3_unchecked_function, "avm2/unchecked_function", 1),
     (as3_url_loader, "avm2/url_loader", 1),
     (as3_urshift, "avm2/urshift", 1),
+    (as3_vector3d_products, "avm2/vector3d_products", 1),
     (as3_vector_coercion, "avm2/vector_coercion", 1),
     (as3_vector_concat, "avm2/vector_concat", 1),
     (as3_vector_constr, "avm2/vector_constr", 1),
@@ -869,6 +959,21 @@ swf_tests_approx! {
     (as3_matrix, "avm2/matrix", 1, @num_patterns = &[
         Regex::new(r"\(a=(.+?), b=(.+?), c=(.+?), d=(.+?), tx=(.+?), ty=(.+?)\)").unwrap()
     ], max_relative = f32::EPSILON as f64),
+    #[ignore] (as3_matrix_concat, "avm2/matrix_concat", 1, @num_patterns = &[
+        Regex::new(r"\(a=(.+?), b=(.+?), c=(.+?), d=(.+?), tx=(.+?), ty=(.+?)\)").unwrap()
+    ], max_relative = f32::EPSILON as f64), //Ignored because the expected output was written by hand, not captured from Flash Player
+    #[ignore] (as3_matrix_createbox, "avm2/matrix_createbox", 1, @num_patterns = &[
+        Regex::new(r"\(a=(.+?), b=(.+?), c=(.+?), d=(.+?), tx=(.+?), ty=(.+?)\)").unwrap()
+    ], max_relative = f32::EPSILON as f64), //Ignored because the expected output was written by hand, not captured from Flash Player
+    #[ignore] (as3_matrix_creategradientbox, "avm2/matrix_creategradientbox", 1, @num_patterns = &[
+        Regex::new(r"\(a=(.+?), b=(.+?), c=(.+?), d=(.+?), tx=(.+?), ty=(.+?)\)").unwrap()
+    ], max_relative = f32::EPSILON as f64), //Ignored because the expected output was written by hand, not captured from Flash Player
+    #[ignore] (as3_matrix_invert, "avm2/matrix_invert", 1, @num_patterns = &[
+        Regex::new(r"\(a=(.+?), b=(.+?), c=(.+?), d=(.+?), tx=(.+?), ty=(.+?)\)").unwrap()
+    ], max_relative = f32::EPSILON as f64), //Ignored because the expected output was written by hand, not captured from Flash Player
+    #[ignore] (as3_matrix_rotate_scale_translate, "avm2/matrix_rotate_scale_translate", 1, @num_patterns = &[
+        Regex::new(r"\(a=(.+?), b=(.+?), c=(.+?), d=(.+?), tx=(.+?), ty=(.+?)\)").unwrap()
+    ], max_relative = f32::EPSILON as f64), //Ignored because the expected output was written by hand, not captured from Flash Player
     (as3_number_toexponential, "avm2/number_toexponential", 1, max_relative = 0.001),
     (as3_number_tofixed, "avm2/number_tofixed", 1, max_relative = 0.001),
     (as3_number_toprecision, "avm2/number_toprecision", 1, max_relative = 0.001),
@@ -1068,6 +1173,129 @@ fn timeout_avm1() -> Result<(), Error> {
     )
 }
 
+#[test]
+fn date_current_time_override_avm2() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/date_current_time_override/test.swf",
+        1,
+        "tests/swfs/avm2/date_current_time_override/input.json",
+        "tests/swfs/avm2/date_current_time_override/output.txt",
+        |_| {
+            set_current_date_time_override(Some(Utc.ymd(2020, 6, 15).and_hms(12, 0, 0)));
+            Ok(())
+        },
+        |_| {
+            set_current_date_time_override(None);
+            Ok(())
+        },
+        false,
+        false,
+    )
+}
+
+#[test]
+fn date_utc_setters_dst_avm2() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/date_utc_setters_dst/test.swf",
+        1,
+        "tests/swfs/avm2/date_utc_setters_dst/input.json",
+        "tests/swfs/avm2/date_utc_setters_dst/output.txt",
+        |_| {
+            set_timezone_override(Some(chrono_tz::America::New_York.into()));
+            Ok(())
+        },
+        |_| {
+            set_timezone_override(None);
+            Ok(())
+        },
+        false,
+        false,
+    )
+}
+
+#[test]
+fn date_parse_iso_timezone_avm2() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/date_parse_iso_timezone/test.swf",
+        1,
+        "tests/swfs/avm2/date_parse_iso_timezone/input.json",
+        "tests/swfs/avm2/date_parse_iso_timezone/output.txt",
+        |_| {
+            set_timezone_override(Some(chrono_tz::America::New_York.into()));
+            Ok(())
+        },
+        |_| {
+            set_timezone_override(None);
+            Ok(())
+        },
+        false,
+        false,
+    )
+}
+
+#[test]
+fn date_tostring_negative_offset_avm2() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/date_tostring_negative_offset/test.swf",
+        1,
+        "tests/swfs/avm2/date_tostring_negative_offset/input.json",
+        "tests/swfs/avm2/date_tostring_negative_offset/output.txt",
+        |_| {
+            set_timezone_override(Some(chrono_tz::America::New_York.into()));
+            Ok(())
+        },
+        |_| {
+            set_timezone_override(None);
+            Ok(())
+        },
+        false,
+        false,
+    )
+}
+
+#[test]
+fn date_tostring_positive_offset_avm2() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/date_tostring_positive_offset/test.swf",
+        1,
+        "tests/swfs/avm2/date_tostring_positive_offset/input.json",
+        "tests/swfs/avm2/date_tostring_positive_offset/output.txt",
+        |_| {
+            set_timezone_override(Some(chrono_tz::Asia::Tokyo.into()));
+            Ok(())
+        },
+        |_| {
+            set_timezone_override(None);
+            Ok(())
+        },
+        false,
+        false,
+    )
+}
+
+#[test]
+fn date_tolocalestring_24_hour_avm2() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/date_tolocalestring_24_hour/test.swf",
+        1,
+        "tests/swfs/avm2/date_tolocalestring_24_hour/input.json",
+        "tests/swfs/avm2/date_tolocalestring_24_hour/output.txt",
+        |player| {
+            player.lock().unwrap().set_locale(Locale::EUROPEAN);
+            Ok(())
+        },
+        |_| Ok(()),
+        false,
+        false,
+    )
+}
+
 #[test]
 fn stage_scale_mode() -> Result<(), Error> {
     set_logger();
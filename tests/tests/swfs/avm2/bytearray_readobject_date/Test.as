@@ -0,0 +1,35 @@
+package {
+	import flash.display.MovieClip;
+	import flash.net.ObjectEncoding;
+	import flash.utils.ByteArray;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var times:Array = [0, 86400000, -1000, 1234567890123.9];
+			for each (var time:Number in times) {
+				var amf3:ByteArray = new ByteArray();
+				amf3.writeByte(0x08);
+				amf3.writeByte(0x01);
+				amf3.writeDouble(time);
+				amf3.position = 0;
+				trace("// AMF3 date " + time);
+				dump(amf3.readObject());
+
+				var amf0:ByteArray = new ByteArray();
+				amf0.objectEncoding = ObjectEncoding.AMF0;
+				amf0.writeByte(0x0B);
+				amf0.writeDouble(time);
+				amf0.writeShort(0);
+				amf0.position = 0;
+				trace("// AMF0 date " + time);
+				dump(amf0.readObject());
+			}
+		}
+
+		private function dump(value:*):void {
+			trace("is Date: " + (value is Date));
+			trace("time: " + value.time);
+			trace("UTC: " + value.fullYearUTC + "-" + (value.monthUTC + 1) + "-" + value.dateUTC + " " + value.hoursUTC + ":" + value.minutesUTC + ":" + value.secondsUTC + "." + value.millisecondsUTC);
+		}
+	}
+}
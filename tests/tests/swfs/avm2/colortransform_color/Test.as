@@ -0,0 +1,39 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.ColorTransform;
+
+var ct:ColorTransform = new ColorTransform(0.5, 0.5, 0.5, 0.5, 1, 2, 3, 4);
+
+trace("// new ColorTransform(0.5, 0.5, 0.5, 0.5, 1, 2, 3, 4).color");
+trace(ct.color.toString(16));
+trace("");
+
+trace("// ct.color = 0xFF0000");
+ct.color = 0xFF0000;
+trace(ct);
+trace("redOffset:", ct.redOffset);
+trace("greenOffset:", ct.greenOffset);
+trace("blueOffset:", ct.blueOffset);
+trace("redMultiplier:", ct.redMultiplier);
+trace("greenMultiplier:", ct.greenMultiplier);
+trace("blueMultiplier:", ct.blueMultiplier);
+trace("ct.color:", ct.color.toString(16));
+trace("");
+
+trace("// ct.color = 0x123456");
+ct.color = 0x123456;
+trace(ct);
+trace("ct.color:", ct.color.toString(16));
+trace("");
+
+trace("// ct.color = 0xAABBCCDD (alpha byte is ignored)");
+ct.color = 0xAABBCCDD;
+trace(ct);
+trace("ct.color:", ct.color.toString(16));
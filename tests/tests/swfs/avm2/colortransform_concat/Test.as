@@ -0,0 +1,50 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.ColorTransform;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var halfRed:ColorTransform = new ColorTransform(0.5, 1, 1, 1, 0, 0, 0, 0);
+			var greenOffset:ColorTransform = new ColorTransform(1, 1, 1, 1, 0, 64, 0, 0);
+			var redOffset:ColorTransform = new ColorTransform(1, 1, 1, 1, 100, 0, 0, 0);
+
+			trace("// halfRed.concat(greenOffset)");
+			var result:ColorTransform = new ColorTransform(0.5, 1, 1, 1, 0, 0, 0, 0);
+			result.concat(greenOffset);
+			trace(result);
+			trace("redMultiplier:", result.redMultiplier);
+			trace("greenMultiplier:", result.greenMultiplier);
+			trace("blueMultiplier:", result.blueMultiplier);
+			trace("alphaMultiplier:", result.alphaMultiplier);
+			trace("redOffset:", result.redOffset);
+			trace("greenOffset:", result.greenOffset);
+			trace("blueOffset:", result.blueOffset);
+			trace("alphaOffset:", result.alphaOffset);
+			trace("");
+
+			trace("// halfRed.concat(redOffset)");
+			result = new ColorTransform(0.5, 1, 1, 1, 0, 0, 0, 0);
+			result.concat(redOffset);
+			trace(result);
+			trace("");
+
+			trace("// redOffset.concat(halfRed)");
+			result = new ColorTransform(1, 1, 1, 1, 100, 0, 0, 0);
+			result.concat(halfRed);
+			trace(result);
+			trace("");
+
+			trace("// argument is not modified");
+			trace(halfRed);
+			trace("");
+
+			trace("// applied to a display object");
+			var sprite:Sprite = new Sprite();
+			result = new ColorTransform(0.5, 1, 1, 0.5, 0, 0, 0, 0);
+			result.concat(new ColorTransform(1, 1, 1, 1, 20, 64, 0, -10));
+			sprite.transform.colorTransform = result;
+			trace(sprite.transform.colorTransform);
+		}
+	}
+}
@@ -0,0 +1,31 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.ColorTransform;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var ct:ColorTransform = new ColorTransform(2, 2, 2, 1, 300, -300, 0, 0);
+			trace("// new ColorTransform(2, 2, 2, 1, 300, -300, 0, 0)");
+			trace(ct);
+
+			var s:Sprite = new Sprite();
+			s.transform.colorTransform = ct;
+			trace("// after applying to a display object");
+			trace(s.transform.colorTransform);
+
+			ct = new ColorTransform(-3, 0.5, 4, 1, 255, -255, 1000, -1000);
+			s.transform.colorTransform = ct;
+			trace("// new ColorTransform(-3, 0.5, 4, 1, 255, -255, 1000, -1000) applied");
+			trace(s.transform.colorTransform);
+
+			var args:Array = ["2", true, null, undefined, "12", "abc", 7.9, -7.9];
+			ct = new ColorTransform(args[0], args[1], args[2], args[3], args[4], args[5], args[6], args[7]);
+			trace("// new ColorTransform(\"2\", true, null, undefined, \"12\", \"abc\", 7.9, -7.9)");
+			trace(ct);
+			s.transform.colorTransform = ct;
+			trace("// after applying to a display object");
+			trace(s.transform.colorTransform);
+		}
+	}
+}
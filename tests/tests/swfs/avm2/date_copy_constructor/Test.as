@@ -0,0 +1,26 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var a:Date = new Date(2021, 5, 15, 12, 30, 45, 123);
+			var b:Date = new Date(a);
+			trace("/// new Date(valid date)");
+			trace(b.time === a.time);
+			trace(b.time);
+			trace(b);
+
+			b.setFullYear(1999);
+			trace("/// modifying the copy leaves the original alone");
+			trace(a.fullYear);
+			trace(b.fullYear);
+
+			var invalid:Date = new Date(NaN);
+			var c:Date = new Date(invalid);
+			trace("/// new Date(invalid date)");
+			trace(invalid.time);
+			trace(c.time);
+			trace(c);
+		}
+	}
+}
@@ -0,0 +1,14 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			// The test harness pins the current time to 2020-06-15T12:00:00Z.
+			var now:Date = new Date();
+			trace("/// new Date()");
+			trace(now.time);
+			trace(now.toUTCString());
+			trace(now);
+		}
+	}
+}
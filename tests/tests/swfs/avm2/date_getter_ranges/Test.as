@@ -0,0 +1,51 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var timestamps:Array = [];
+			// A spread of timestamps on both sides of the epoch, with odd millisecond parts.
+			for (var i:int = -200; i <= 200; i++) {
+				timestamps.push(i * 987654321987 + i * 7);
+			}
+			timestamps.push(-1, 0, 1, 999, 1000, -999, -1000, -1001);
+
+			check(timestamps, "milliseconds", 0, 999);
+			check(timestamps, "millisecondsUTC", 0, 999);
+			check(timestamps, "seconds", 0, 59);
+			check(timestamps, "secondsUTC", 0, 59);
+			check(timestamps, "minutes", 0, 59);
+			check(timestamps, "minutesUTC", 0, 59);
+			check(timestamps, "hours", 0, 23);
+			check(timestamps, "hoursUTC", 0, 23);
+			check(timestamps, "date", 1, 31);
+			check(timestamps, "dateUTC", 1, 31);
+			check(timestamps, "day", 0, 6);
+			check(timestamps, "dayUTC", 0, 6);
+			check(timestamps, "month", 0, 11);
+			check(timestamps, "monthUTC", 0, 11);
+
+			trace("/// Getters just before the epoch");
+			var d:Date = new Date(-1);
+			trace(d.millisecondsUTC);
+			trace(d.secondsUTC);
+			trace(d.minutesUTC);
+			trace(d.hoursUTC);
+			trace(d.dayUTC);
+			trace(d.monthUTC);
+			trace(d.fullYearUTC);
+		}
+
+		private function check(timestamps:Array, getter:String, min:Number, max:Number):void {
+			var failures:int = 0;
+			for each (var time:Number in timestamps) {
+				var value:Number = new Date(time)[getter];
+				if (value !== Math.floor(value) || value < min || value > max) {
+					trace("Bad " + getter + " for " + time + ": " + value);
+					failures++;
+				}
+			}
+			trace("/// " + getter + " in " + min + ".." + max + ": " + failures + " failures");
+		}
+	}
+}
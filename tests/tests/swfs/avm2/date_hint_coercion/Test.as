@@ -0,0 +1,37 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var d:Date = new Date(86400000);
+			trace("// \"\" + d");
+			trace("" + d);
+			trace("// d + \"x\"");
+			trace(d + "x");
+			trace("// d + 1");
+			trace(d + 1);
+			trace("// d + d");
+			trace(d + d);
+			trace("// d - 0");
+			trace(d - 0);
+			trace("// d * 1");
+			trace(d * 1);
+			trace("// +d");
+			trace(+d);
+			trace("// Number(d)");
+			trace(Number(d));
+			trace("// d.valueOf()");
+			trace(d.valueOf());
+			trace("// d < new Date(86400001)");
+			trace(d < new Date(86400001));
+			trace("// d > new Date(86400001)");
+			trace(d > new Date(86400001));
+
+			var invalid:Date = new Date(NaN);
+			trace("// \"\" + invalid");
+			trace("" + invalid);
+			trace("// invalid - 0");
+			trace(invalid - 0);
+		}
+	}
+}
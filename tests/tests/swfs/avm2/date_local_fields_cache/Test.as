@@ -0,0 +1,36 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var d:Date = new Date(2020, 0, 31, 10, 20, 30, 400);
+			trace("/// new Date(2020, 0, 31, 10, 20, 30, 400)");
+			dump(d);
+			dump(d);
+
+			trace("/// setMonth(1)");
+			d.setMonth(1);
+			dump(d);
+
+			trace("/// time = 0");
+			d.time = 0;
+			dump(d);
+
+			trace("/// setUTCHours(20)");
+			d.setUTCHours(20);
+			dump(d);
+
+			trace("/// fullYear = 2000");
+			d.fullYear = 2000;
+			dump(d);
+
+			trace("/// setTime(NaN)");
+			d.setTime(NaN);
+			dump(d);
+		}
+
+		private function dump(d:Date):void {
+			trace(d.fullYear, d.month, d.date, d.hours, d.minutes, d.seconds, d.milliseconds, d.day);
+		}
+	}
+}
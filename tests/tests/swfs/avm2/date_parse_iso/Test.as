@@ -0,0 +1,46 @@
+package {
+	public class Test {
+	}
+}
+
+function testParser(date) {
+	trace("TESTING", date);
+	var milliseconds = Date.parse(date);
+	if (isNaN(milliseconds)) {
+		trace("Failed.");
+		return;
+	}
+	trace(milliseconds);
+	var date = new Date(milliseconds);
+	trace(date.fullYearUTC, date.monthUTC, date.dateUTC, date.hoursUTC, date.minutesUTC, date.secondsUTC, date.millisecondsUTC);
+}
+
+trace("// TEST UTC");
+testParser("2021-03-14T15:09:26Z");
+testParser("2021-03-14T15:09Z");
+
+trace("// TEST OFFSETS");
+testParser("2021-03-14T15:09:26+05:30");
+testParser("2021-03-14T15:09:26-08:00");
+testParser("2021-03-14T15:09:26+00:00");
+
+trace("// TEST FRACTIONAL SECONDS");
+testParser("2021-03-14T15:09:26.535Z");
+testParser("2021-03-14T15:09:26.5-08:00");
+testParser("2021-03-14T15:09:26.123456Z");
+
+trace("// TEST LOCAL TIME");
+testParser("2021-03-14T15:09:26");
+testParser("2021-03-14T15:09:26.535");
+
+trace("// TEST DATE ONLY (UTC)");
+testParser("2021-03-14");
+
+trace("// TEST INVALID DATES");
+testParser("2021-03-14T15:09:26.Z");
+testParser("2021-03-14T15:09.535Z");
+testParser("2021-03-14T15:09:26+0530");
+testParser("2021-3-14T15:09:26Z");
+testParser("2021-03-14T");
+testParser("2021-03-14T15:09:26Z GMT-0700");
+testParser("2021-03-14T15:09:26Z 10:00:00");
@@ -0,0 +1,22 @@
+package {
+	public class Test {
+	}
+}
+
+// This test runs with the timezone set to America/New_York.
+
+function testParser(date) {
+	trace("TESTING", date);
+	trace(Date.parse(date));
+}
+
+trace("// TEST DATE ONLY (UTC)");
+testParser("2021-03-14");
+testParser("2021-01-10");
+
+trace("// TEST DATE-TIME WITHOUT OFFSET (LOCAL)");
+testParser("2021-03-14T15:09:26");
+testParser("2021-01-10T00:00");
+
+trace("// TEST DATE-TIME WITH OFFSET");
+testParser("2021-03-14T15:09:26Z");
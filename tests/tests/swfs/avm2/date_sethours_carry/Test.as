@@ -0,0 +1,26 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			check("setHours(0, 0, 0, 1000)", function(d:Date):Number { return d.setHours(0, 0, 0, 1000); });
+			check("setHours(25)", function(d:Date):Number { return d.setHours(25); });
+			check("setHours(25, 61, 61, 1001)", function(d:Date):Number { return d.setHours(25, 61, 61, 1001); });
+			check("setHours(-1)", function(d:Date):Number { return d.setHours(-1); });
+			check("setMinutes(90)", function(d:Date):Number { return d.setMinutes(90); });
+			check("setMinutes(0, 3600)", function(d:Date):Number { return d.setMinutes(0, 3600); });
+			check("setSeconds(120)", function(d:Date):Number { return d.setSeconds(120); });
+			check("setSeconds(-1, 500)", function(d:Date):Number { return d.setSeconds(-1, 500); });
+			check("setMilliseconds(-1)", function(d:Date):Number { return d.setMilliseconds(-1); });
+			check("setMilliseconds(86400000)", function(d:Date):Number { return d.setMilliseconds(86400000); });
+		}
+
+		private function check(name:String, setter:*):void {
+			var d:Date = new Date(2021, 0, 15, 10, 20, 30, 400);
+			var returned:Number = setter(d);
+			trace("/// " + name);
+			trace(d + " +" + d.milliseconds + "ms");
+			trace(returned == d.time);
+		}
+	}
+}
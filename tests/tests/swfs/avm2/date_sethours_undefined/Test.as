@@ -0,0 +1,50 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+function fields(d:Date):String {
+	return d.getHours() + ":" + d.getMinutes() + ":" + d.getSeconds() + "." + d.getMilliseconds();
+}
+
+var d:Date;
+
+trace("/// setHours(5)");
+d = new Date(2000, 0, 1, 10, 20, 30, 400);
+d.setHours(5);
+trace(fields(d));
+trace("");
+
+trace("/// setHours(5, 10)");
+d = new Date(2000, 0, 1, 10, 20, 30, 400);
+d.setHours(5, 10);
+trace(fields(d));
+trace("");
+
+trace("/// setHours(5, 10, 15, 20)");
+d = new Date(2000, 0, 1, 10, 20, 30, 400);
+d.setHours(5, 10, 15, 20);
+trace(fields(d));
+trace("");
+
+trace("/// setHours(5, undefined, 30)");
+d = new Date(2000, 0, 1, 10, 20, 30, 400);
+trace("Return value:", d.setHours(5, undefined, 30));
+trace(d);
+trace(fields(d));
+trace("");
+
+trace("/// setHours(5, 10, undefined)");
+d = new Date(2000, 0, 1, 10, 20, 30, 400);
+trace("Return value:", d.setHours(5, 10, undefined));
+trace(d);
+trace("");
+
+trace("/// setMinutes(undefined, 45)");
+d = new Date(2000, 0, 1, 10, 20, 30, 400);
+trace("Return value:", d.setMinutes(undefined, 45));
+trace(d);
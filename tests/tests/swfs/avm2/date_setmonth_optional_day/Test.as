@@ -0,0 +1,42 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var d:Date = new Date(2021, 0, 20, 10, 0, 0);
+			dump("new Date(2021, 0, 20, 10)", d);
+
+			d.setMonth(5, 15);
+			dump("setMonth(5, 15)", d);
+
+			d.setMonth(2);
+			dump("setMonth(2)", d);
+
+			d.date = 31;
+			d.setMonth(1);
+			dump("date = 31, setMonth(1)", d);
+
+			d.setMonth(13);
+			dump("setMonth(13)", d);
+
+			d.month = 4;
+			dump("month = 4", d);
+
+			d.setFullYear(2020, 1);
+			dump("setFullYear(2020, 1)", d);
+
+			d.setUTCMonth(7);
+			dump("setUTCMonth(7)", d);
+
+			d.setUTCMonth(8, 9);
+			dump("setUTCMonth(8, 9)", d);
+
+			d.setMonth(5, undefined);
+			dump("setMonth(5, undefined)", d);
+		}
+
+		private function dump(label:String, d:Date):void {
+			trace(label + ":", d.fullYear, d.month, d.date, d.hours);
+		}
+	}
+}
@@ -0,0 +1,38 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("/// setUTCMonth on 2020-06-15");
+			var months:Array = [-1, 12, -12, -13, 23, 25, 0, 11];
+			for each (var month:int in months) {
+				var d:Date = new Date(Date.UTC(2020, 5, 15, 12));
+				d.setUTCMonth(month);
+				trace("setUTCMonth(" + month + "): " + utc(d));
+			}
+
+			trace("/// setMonth on 2020-06-15 (local)");
+			for each (month in months) {
+				d = new Date(2020, 5, 15, 12);
+				d.setMonth(month);
+				trace("setMonth(" + month + "): " + local(d));
+			}
+
+			trace("/// Day overflow after a month change");
+			d = new Date(Date.UTC(2020, 0, 31));
+			d.setUTCMonth(1);
+			trace("2020-01-31 setUTCMonth(1): " + utc(d));
+			d = new Date(Date.UTC(2020, 0, 31));
+			d.setUTCMonth(-11);
+			trace("2020-01-31 setUTCMonth(-11): " + utc(d));
+		}
+
+		private function utc(d:Date):String {
+			return d.fullYearUTC + "-" + (d.monthUTC + 1) + "-" + d.dateUTC + " " + d.hoursUTC + "h";
+		}
+
+		private function local(d:Date):String {
+			return d.fullYear + "-" + (d.month + 1) + "-" + d.date + " " + d.hours + "h";
+		}
+	}
+}
@@ -0,0 +1,48 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+// Each setter, with the number of arguments it accepts
+var setters:Array = [
+	["setMilliseconds", 1],
+	["setSeconds", 2],
+	["setMinutes", 3],
+	["setHours", 4],
+	["setDate", 1],
+	["setMonth", 2],
+	["setFullYear", 3],
+	["setUTCMilliseconds", 1],
+	["setUTCSeconds", 2],
+	["setUTCMinutes", 3],
+	["setUTCHours", 4],
+	["setUTCDate", 1],
+	["setUTCMonth", 2],
+	["setUTCFullYear", 3],
+	["setTime", 1]
+];
+
+var badValues:Array = [NaN, Infinity, -Infinity];
+
+for each (var setter:Array in setters) {
+	var name:String = setter[0];
+	var count:int = setter[1];
+	trace("/// " + name);
+	for each (var bad:Number in badValues) {
+		for (var pos:int = 0; pos < count; pos++) {
+			var args:Array = [];
+			for (var i:int = 0; i < count; i++) {
+				args.push(i == pos ? bad : 1);
+			}
+			var d:Date = new Date(2000, 0, 1, 10, 20, 30, 400);
+			var result:* = d[name].apply(d, args);
+			trace(name + "(" + args.join(", ") + "): returned " + result + ", getTime() " + d.getTime());
+		}
+	}
+
+	trace("");
+}
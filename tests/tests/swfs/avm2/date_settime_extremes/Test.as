@@ -0,0 +1,17 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var values:Array = [8.64e15, -8.64e15, 8.64e15 + 1, -8.64e15 - 1, 8.64e15 + 0.5];
+			for each (var value:Number in values) {
+				var d:Date = new Date(0);
+				var returned:Number = d.setTime(value);
+				trace("/// setTime(" + value + ")");
+				trace(returned);
+				trace(d.getTime());
+				trace(d.valueOf() === returned || (isNaN(returned) && isNaN(d.valueOf())));
+			}
+		}
+	}
+}
@@ -0,0 +1,36 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+var values:Array = [
+	0,
+	1.9,
+	-1.9,
+	0.5,
+	-0.5,
+	1234567.999,
+	8e15,
+	-8e15,
+	8.64e15 + 1,
+	-8.64e15 - 1,
+	9.2e18,
+	-9.2e18,
+	1e300
+];
+
+trace("/// setTime");
+for each (var value:Number in values) {
+	var d:Date = new Date(0);
+	trace("setTime(" + value + "): returned " + d.setTime(value) + ", getTime() " + d.getTime());
+}
+trace("");
+
+trace("/// new Date(time)");
+for each (value in values) {
+	trace("new Date(" + value + ").getTime(): " + new Date(value).getTime());
+}
@@ -0,0 +1,23 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var values:Array = [1000, 1.9, -1.9, Infinity, -Infinity, NaN, 5];
+
+			trace("/// setTime(value)");
+			var d:Date = new Date(0);
+			for each (var value:Number in values) {
+				var returned:Number = d.setTime(value);
+				trace("setTime(" + value + ") returned " + returned + ", time " + d.time + ", " + d.toUTCString());
+			}
+
+			trace("/// time = value");
+			d = new Date(0);
+			for each (value in values) {
+				var assigned:Number = (d.time = value);
+				trace("time = " + value + " evaluated to " + assigned + ", getTime() " + d.getTime() + ", " + d.toUTCString());
+			}
+		}
+	}
+}
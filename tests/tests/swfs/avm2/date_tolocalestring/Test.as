@@ -0,0 +1,21 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			// The test player uses the default US locale.
+			var dates:Array = [
+				new Date(2001, 1, 3, 4, 5, 6),
+				new Date(2001, 1, 3, 16, 5, 6),
+				new Date(2001, 1, 3, 0, 0, 0),
+				new Date(2001, 1, 3, 12, 30, 0),
+				new Date(NaN)
+			];
+			for each (var d:Date in dates) {
+				trace(d.toLocaleString());
+				trace(d.toLocaleDateString());
+				trace(d.toLocaleTimeString());
+			}
+		}
+	}
+}
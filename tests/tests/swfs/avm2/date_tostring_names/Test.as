@@ -0,0 +1,28 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		// Reference abbreviations, as printed by Flash Player.
+		private static const DAYS:Array = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+		private static const MONTHS:Array = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+		public function Test() {
+			trace("/// Weekdays");
+			// 2021-02-07 was a Sunday.
+			for (var day:int = 0; day < 7; day++) {
+				check(new Date(2021, 1, 7 + day, 4, 5, 6));
+			}
+
+			trace("/// Months");
+			for (var month:int = 0; month < 12; month++) {
+				check(new Date(2021, month, month * 2 + 1, month + 9, 0, 9));
+			}
+		}
+
+		private function check(d:Date):void {
+			var str:String = d.toString();
+			var expected:String = DAYS[d.day] + " " + MONTHS[d.month] + " " + d.date + " ";
+			trace(str, str.indexOf(expected) == 0 ? "ok" : "MISMATCH, expected prefix " + expected);
+		}
+	}
+}
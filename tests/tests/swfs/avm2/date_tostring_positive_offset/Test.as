@@ -0,0 +1,21 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			// 2021-01-15 and 2021-07-15 at 12:00 UTC, so that one is in DST where the zone has it.
+			var winter:Date = new Date(1610712000000);
+			var summer:Date = new Date(1626350400000);
+
+			trace("/// winter");
+			trace(winter.toString());
+			trace(winter.toTimeString());
+			trace(winter.timezoneOffset);
+
+			trace("/// summer");
+			trace(summer.toString());
+			trace(summer.toTimeString());
+			trace(summer.timezoneOffset);
+		}
+	}
+}
@@ -0,0 +1,58 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			// The test harness runs this in America/New_York, which observes DST.
+			var winter:Number = Date.UTC(2021, 0, 15, 12, 0, 0);
+
+			var local:Date = new Date(winter);
+			local.setMonth(6);
+			var utc:Date = new Date(winter);
+			utc.setUTCMonth(6);
+			trace("/// setMonth(6) vs setUTCMonth(6) from January 15th 12:00 UTC");
+			dump(local);
+			dump(utc);
+			trace(utc.time - local.time);
+
+			var endOfJanuary:Number = Date.UTC(2021, 0, 31, 12, 0, 0);
+			local = new Date(endOfJanuary);
+			local.setMonth(1);
+			utc = new Date(endOfJanuary);
+			utc.setUTCMonth(1);
+			trace("/// setMonth(1) vs setUTCMonth(1) from January 31st rolls over into March");
+			dump(local);
+			dump(utc);
+
+			var leapDay:Number = Date.UTC(2020, 1, 29, 12, 0, 0);
+			local = new Date(leapDay);
+			local.setFullYear(2021);
+			utc = new Date(leapDay);
+			utc.setUTCFullYear(2021);
+			trace("/// setFullYear(2021) vs setUTCFullYear(2021) from February 29th");
+			dump(local);
+			dump(utc);
+
+			trace("/// new Date(2021, 2, 20, 12) after the clocks went forward");
+			dump(new Date(2021, 2, 20, 12));
+
+			local = new Date(Date.UTC(2021, 2, 20, 16, 0, 0));
+			trace("/// setHours(9), then setDate(28) in March, after the clocks went forward");
+			local.setHours(9);
+			dump(local);
+			local.setDate(28);
+			dump(local);
+
+			local = new Date(Date.UTC(2021, 10, 1, 16, 0, 0));
+			trace("/// setDate(20), then setHours(8) from November 1st, across the clocks going back");
+			local.setDate(20);
+			dump(local);
+			local.setHours(8);
+			dump(local);
+		}
+
+		private function dump(date:Date):void {
+			trace(date.time + " local " + date.month + "/" + date.date + " " + date.hours + ":00, UTC " + date.monthUTC + "/" + date.dateUTC + " " + date.hoursUTC + ":00");
+		}
+	}
+}
@@ -0,0 +1,62 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+var d:Date;
+
+trace("/// setFullYear(1e12)");
+d = new Date(2000, 0, 1);
+trace("Return value:", d.setFullYear(1e12));
+trace(d);
+trace("");
+
+trace("/// setFullYear(-1e12)");
+d = new Date(2000, 0, 1);
+trace("Return value:", d.setFullYear(-1e12));
+trace(d);
+trace("");
+
+trace("/// setFullYear(4294969296)");
+// This is 2000 + 2^32, which would wrap around to 2000 in a 32-bit integer
+d = new Date(2000, 0, 1);
+trace("Return value:", d.setFullYear(4294969296));
+trace(d);
+trace("");
+
+trace("/// setUTCFullYear(1e12)");
+d = new Date(2000, 0, 1);
+trace("Return value:", d.setUTCFullYear(1e12));
+trace(d);
+trace("");
+
+trace("/// setMonth(12e12)");
+d = new Date(2000, 0, 1);
+trace("Return value:", d.setMonth(12e12));
+trace(d);
+trace("");
+
+trace("/// setDate(1e300)");
+d = new Date(2000, 0, 1);
+trace("Return value:", d.setDate(1e300));
+trace(d);
+trace("");
+
+trace("/// setHours(-1e300)");
+d = new Date(2000, 0, 1);
+trace("Return value:", d.setHours(-1e300));
+trace(d);
+trace("");
+
+trace("/// setMilliseconds(1e300)");
+d = new Date(2000, 0, 1);
+trace("Return value:", d.setMilliseconds(1e300));
+trace(d);
+trace("");
+
+trace("/// new Date(1e12, 0)");
+trace(new Date(1e12, 0).getTime());
@@ -0,0 +1,43 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.accessibility.AccessibilityProperties;
+import flash.display.Sprite;
+
+var sprite:Sprite = new Sprite();
+
+trace("/// accessibilityProperties (unset)");
+trace(sprite.accessibilityProperties);
+trace("");
+
+trace("/// accessibilityProperties = props");
+var props:AccessibilityProperties = new AccessibilityProperties();
+props.name = "Submit button";
+props.description = "Sends the form";
+props.silent = true;
+sprite.accessibilityProperties = props;
+trace("same object:", sprite.accessibilityProperties === props);
+trace("name:", sprite.accessibilityProperties.name);
+trace("description:", sprite.accessibilityProperties.description);
+trace("silent:", sprite.accessibilityProperties.silent);
+trace("forceSimple:", sprite.accessibilityProperties.forceSimple);
+trace("");
+
+trace("/// modifying props after assignment");
+props.name = "Cancel button";
+trace("name:", sprite.accessibilityProperties.name);
+trace("");
+
+trace("/// other objects are unaffected");
+trace(new Sprite().accessibilityProperties);
+trace("");
+
+trace("/// accessibilityProperties = null");
+sprite.accessibilityProperties = null;
+trace(sprite.accessibilityProperties);
@@ -0,0 +1,43 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.ColorTransform;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var s:Sprite = new Sprite();
+			dump("new Sprite()", s);
+
+			s.alpha = 0.5;
+			dump("alpha = 0.5", s);
+
+			var ct:ColorTransform = s.transform.colorTransform;
+			ct.alphaMultiplier = 0.25;
+			ct.redMultiplier = 0.5;
+			s.transform.colorTransform = ct;
+			dump("colorTransform.alphaMultiplier = 0.25, redMultiplier = 0.5", s);
+
+			s.alpha = 0.75;
+			dump("alpha = 0.75", s);
+
+			ct = s.transform.colorTransform;
+			ct.alphaOffset = 10;
+			s.transform.colorTransform = ct;
+			dump("colorTransform.alphaOffset = 10", s);
+
+			s.alpha = 0;
+			dump("alpha = 0", s);
+
+			ct = new ColorTransform(1, 1, 1, 0.5);
+			s.transform.colorTransform = ct;
+			dump("colorTransform = new ColorTransform(1, 1, 1, 0.5)", s);
+		}
+
+		private function dump(label:String, s:Sprite):void {
+			var ct:ColorTransform = s.transform.colorTransform;
+			trace("/// " + label);
+			trace("alpha:", s.alpha, "alphaMultiplier:", ct.alphaMultiplier,
+				"alphaOffset:", ct.alphaOffset, "redMultiplier:", ct.redMultiplier);
+		}
+	}
+}
@@ -0,0 +1,32 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.geom.ColorTransform;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var shape:Shape = new Shape();
+			addChild(shape);
+
+			trace("/// transform.colorTransform = new ColorTransform(1, 1, 1, 0.25)");
+			shape.transform.colorTransform = new ColorTransform(1, 1, 1, 0.25);
+			trace(shape.alpha);
+
+			trace("/// Changing the other components keeps alpha");
+			shape.transform.colorTransform = new ColorTransform(0.5, 0.5, 0.5, 0.25, 10, 20, 30, 40);
+			trace(shape.alpha);
+
+			trace("/// alphaMultiplier above 1");
+			shape.transform.colorTransform = new ColorTransform(1, 1, 1, 2);
+			trace(shape.alpha);
+
+			trace("/// Negative alphaMultiplier");
+			shape.transform.colorTransform = new ColorTransform(1, 1, 1, -0.5);
+			trace(shape.alpha);
+
+			trace("/// Setting alpha afterwards updates alphaMultiplier");
+			shape.alpha = 0.75;
+			trace(shape.transform.colorTransform.alphaMultiplier);
+		}
+	}
+}
@@ -0,0 +1,38 @@
+package {
+	import flash.display.BlendMode;
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var modes:Array = [
+				BlendMode.NORMAL,
+				BlendMode.LAYER,
+				BlendMode.MULTIPLY,
+				BlendMode.SCREEN,
+				BlendMode.LIGHTEN,
+				BlendMode.DARKEN,
+				BlendMode.ADD,
+				BlendMode.SUBTRACT,
+				BlendMode.DIFFERENCE,
+				BlendMode.INVERT,
+				BlendMode.OVERLAY,
+				BlendMode.HARDLIGHT,
+				BlendMode.ALPHA,
+				BlendMode.ERASE
+			];
+
+			var sprite:Sprite = new Sprite();
+			trace("default:", sprite.blendMode);
+			for each (var mode:String in modes) {
+				sprite.blendMode = mode;
+				trace(mode, "->", sprite.blendMode, sprite.blendMode === mode);
+			}
+
+			// Setting an unknown mode throws Error #2008 and stops this script.
+			trace("/// sprite.blendMode = \"bogus\"");
+			sprite.blendMode = "bogus";
+			trace("unreachable");
+		}
+	}
+}
@@ -0,0 +1,90 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.display.Sprite;
+	import flash.geom.Rectangle;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var root:Sprite = new Sprite();
+			var inner:Sprite = new Sprite();
+			var leaf:Shape = square(10);
+			inner.addChild(leaf);
+			root.addChild(inner);
+			addChild(root);
+
+			report("/// Initial", root);
+			report("/// Repeated query", root);
+
+			leaf.x = 50;
+			report("/// Grandchild moved", root);
+
+			inner.scaleX = 2;
+			report("/// Child scaled", root);
+
+			inner.rotation = 90;
+			report("/// Child rotated", root);
+			inner.rotation = 0;
+			inner.scaleX = 1;
+
+			leaf.graphics.beginFill(0x00FF00);
+			leaf.graphics.drawRect(0, 0, 40, 40);
+			leaf.graphics.endFill();
+			report("/// Grandchild redrawn", root);
+
+			var extra:Shape = square(5);
+			extra.x = -20;
+			extra.y = -20;
+			inner.addChild(extra);
+			report("/// Grandchild added", root);
+
+			inner.removeChild(extra);
+			report("/// Grandchild removed", root);
+
+			root.x = 100;
+			root.y = 100;
+			report("/// Root moved", root);
+			trace("inner.getBounds(root):", inner.getBounds(root));
+			trace("inner.getBounds(this):", inner.getBounds(this));
+			trace("leaf.getBounds(inner):", leaf.getBounds(inner));
+
+			trace("/// Deep tree");
+			var parent:Sprite = root;
+			var deepest:Shape = null;
+			for (var i:int = 0; i < 1000; i++) {
+				var node:Sprite = new Sprite();
+				node.x = 1;
+				parent.addChild(node);
+				parent = node;
+			}
+			deepest = square(1);
+			parent.addChild(deepest);
+			for (var j:int = 0; j < 3; j++) {
+				trace("root.getBounds(this):", root.getBounds(this));
+			}
+			deepest.y = 500;
+			trace("root.getBounds(this):", root.getBounds(this));
+			trace("hitTestPoint(1100.5, 600.5):", root.hitTestPoint(1100.5, 600.5));
+			parent.removeChild(deepest);
+			trace("root.getBounds(this):", root.getBounds(this));
+			trace("hitTestPoint(1100.5, 600.5):", root.hitTestPoint(1100.5, 600.5));
+		}
+
+		private function square(size:Number):Shape {
+			var shape:Shape = new Shape();
+			shape.graphics.beginFill(0xFF0000);
+			shape.graphics.drawRect(0, 0, size, size);
+			shape.graphics.endFill();
+			return shape;
+		}
+
+		private function report(label:String, target:Sprite):void {
+			trace(label);
+			trace("width:", target.width, "height:", target.height);
+			trace("getBounds(this):", target.getBounds(this));
+			trace("hitTestPoint(55, 5):", target.hitTestPoint(55, 5));
+			trace("hitTestPoint(5, 5):", target.hitTestPoint(5, 5));
+			trace("");
+		}
+	}
+}
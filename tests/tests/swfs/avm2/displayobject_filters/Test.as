@@ -0,0 +1,70 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.filters.BlurFilter;
+	import flash.filters.GlowFilter;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			trace("/// Defaults");
+			trace("filters.length:", sprite.filters.length);
+			trace("cacheAsBitmap:", sprite.cacheAsBitmap);
+			trace("");
+
+			trace("/// Two filters");
+			sprite.filters = [new BlurFilter(2, 2, 1), new GlowFilter(0x00FF00)];
+			var filters:Array = sprite.filters;
+			trace("filters.length:", filters.length);
+			trace("filters[0] is BlurFilter:", filters[0] is BlurFilter);
+			trace("filters[1] is GlowFilter:", filters[1] is GlowFilter);
+			trace("cacheAsBitmap:", sprite.cacheAsBitmap);
+			trace("");
+
+			trace("/// Mutating the returned array has no effect");
+			filters[0].blurX = 50;
+			filters.pop();
+			trace("filters.length:", sprite.filters.length);
+			trace("filters[0].blurX:", sprite.filters[0].blurX);
+			trace("sprite.filters == sprite.filters:", sprite.filters == sprite.filters);
+			trace("sprite.filters[0] == sprite.filters[0]:", sprite.filters[0] == sprite.filters[0]);
+			trace("");
+
+			trace("/// Mutating an assigned filter has no effect");
+			var blur:BlurFilter = new BlurFilter(3, 3, 1);
+			sprite.filters = [blur];
+			blur.blurX = 10;
+			trace("filters[0].blurX:", sprite.filters[0].blurX);
+			trace("");
+
+			trace("/// Reassigning a modified array applies it");
+			filters = sprite.filters;
+			filters[0].blurX = 7;
+			sprite.filters = filters;
+			trace("filters[0].blurX:", sprite.filters[0].blurX);
+			trace("");
+
+			trace("/// Assigning []");
+			sprite.filters = [];
+			trace("filters.length:", sprite.filters.length);
+			trace("cacheAsBitmap:", sprite.cacheAsBitmap);
+			trace("");
+
+			trace("/// cacheAsBitmap reverts to its explicit value");
+			sprite.cacheAsBitmap = true;
+			sprite.filters = [new BlurFilter()];
+			trace("cacheAsBitmap:", sprite.cacheAsBitmap);
+			sprite.filters = [];
+			trace("cacheAsBitmap:", sprite.cacheAsBitmap);
+			sprite.cacheAsBitmap = false;
+			trace("cacheAsBitmap:", sprite.cacheAsBitmap);
+			trace("");
+
+			trace("/// Assigning null");
+			sprite.filters = [new BlurFilter()];
+			sprite.filters = null;
+			trace("filters.length:", sprite.filters.length);
+			trace("cacheAsBitmap:", sprite.cacheAsBitmap);
+		}
+	}
+}
@@ -0,0 +1,42 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.filters.DropShadowFilter;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			trace("cacheAsBitmap before:", sprite.cacheAsBitmap);
+
+			sprite.filters = [new DropShadowFilter(12.5, 400, 0xFF0000, 0.5, 6)];
+			var shadow:DropShadowFilter = sprite.filters[0];
+			trace("filters[0] is DropShadowFilter:", shadow is DropShadowFilter);
+			trace("distance:", shadow.distance);
+			trace("angle:", shadow.angle);
+			trace("color:", shadow.color.toString(16));
+			trace("alpha:", shadow.alpha);
+			trace("blurX:", shadow.blurX);
+			trace("// defaulted fields");
+			trace("blurY:", shadow.blurY);
+			trace("strength:", shadow.strength);
+			trace("inner:", shadow.inner);
+			trace("knockout:", shadow.knockout);
+			trace("hideObject:", shadow.hideObject);
+			trace("cacheAsBitmap with filters:", sprite.cacheAsBitmap);
+
+			sprite.filters = [new DropShadowFilter()];
+			shadow = sprite.filters[0];
+			trace("// new DropShadowFilter()");
+			trace("distance:", shadow.distance);
+			trace("angle:", shadow.angle);
+			trace("color:", shadow.color);
+			trace("alpha:", shadow.alpha);
+			trace("blurX:", shadow.blurX);
+			trace("blurY:", shadow.blurY);
+			trace("cacheAsBitmap with filters:", sprite.cacheAsBitmap);
+
+			sprite.filters = null;
+			trace("cacheAsBitmap after clearing filters:", sprite.cacheAsBitmap);
+		}
+	}
+}
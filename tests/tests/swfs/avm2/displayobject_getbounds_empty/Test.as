@@ -0,0 +1,36 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var empty:Sprite = new Sprite();
+			trace("// detached empty sprite");
+			trace("getBounds(empty): " + empty.getBounds(empty));
+			trace("getBounds(null): " + empty.getBounds(null));
+			trace("getBounds(this): " + empty.getBounds(this));
+			trace("getRect(empty): " + empty.getRect(empty));
+
+			empty.x = 50;
+			empty.y = 25;
+			addChild(empty);
+			trace("// attached, moved empty sprite");
+			trace("getBounds(empty): " + empty.getBounds(empty));
+			trace("getBounds(this): " + empty.getBounds(this));
+			trace("getBounds(stage): " + empty.getBounds(stage));
+			trace("getRect(this): " + empty.getRect(this));
+
+			var drawn:Sprite = new Sprite();
+			drawn.graphics.beginFill(0xFF0000);
+			drawn.graphics.drawRect(0, 0, 30, 40);
+			drawn.graphics.endFill();
+			drawn.x = 10;
+			drawn.y = 20;
+			addChild(drawn);
+			trace("// drawn sprite");
+			trace("getBounds(drawn): " + drawn.getBounds(drawn));
+			trace("getBounds(this): " + drawn.getBounds(this));
+			trace("getBounds(empty): " + drawn.getBounds(empty));
+		}
+	}
+}
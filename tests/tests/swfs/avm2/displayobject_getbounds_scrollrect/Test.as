@@ -0,0 +1,50 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.geom.Rectangle;
+
+	public class Test extends MovieClip {
+		private var content:Sprite = new Sprite();
+		private var tick:int = 0;
+
+		public function Test() {
+			content.x = 10;
+			content.y = 20;
+			content.graphics.beginFill(0xFF0000);
+			content.graphics.drawRect(0, 0, 200, 100);
+			content.graphics.endFill();
+			addChild(content);
+
+			trace("/// No scrollRect");
+			traceBounds();
+
+			content.scrollRect = new Rectangle(5, 5, 50, 40);
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		private function onEnterFrame(e:Event):void {
+			tick++;
+			if (tick == 1) {
+				trace("/// scrollRect (5, 5, 50, 40) applied");
+				traceBounds();
+
+				content.scaleX = 2;
+				trace("/// scaleX = 2");
+				traceBounds();
+
+				content.scaleX = 1;
+				content.scrollRect = new Rectangle(0, 0, 400, 300);
+			} else if (tick == 2) {
+				trace("/// scrollRect (0, 0, 400, 300) applied");
+				traceBounds();
+				removeEventListener(Event.ENTER_FRAME, onEnterFrame);
+			}
+		}
+
+		private function traceBounds():void {
+			trace("getBounds(this): " + content.getBounds(this));
+			trace("getBounds(content): " + content.getBounds(content));
+		}
+	}
+}
@@ -0,0 +1,38 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			sprite.graphics.beginFill(0xFF0000);
+			sprite.graphics.drawRect(10, 20, 40, 10);
+			sprite.graphics.endFill();
+			sprite.x = 50;
+			sprite.y = 30;
+			sprite.scaleX = 2;
+			sprite.scaleY = 3;
+			addChild(sprite);
+
+			trace("/// getBounds(self) ignores the object's own transform");
+			trace(sprite.getBounds(sprite));
+			trace(sprite.getRect(sprite));
+			trace("/// getBounds(stage)");
+			trace(sprite.getBounds(stage));
+
+			var child:Shape = new Shape();
+			child.graphics.beginFill(0x00FF00);
+			child.graphics.drawRect(0, 0, 10, 10);
+			child.graphics.endFill();
+			child.x = 100;
+			child.rotation = 90;
+			sprite.addChild(child);
+
+			trace("/// With a transformed child, getBounds(self) still includes the child's transform");
+			trace(sprite.getBounds(sprite));
+			trace(sprite.getBounds(stage));
+			trace(child.getBounds(child));
+		}
+	}
+}
@@ -0,0 +1,49 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.display.Sprite;
+	import flash.geom.Rectangle;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var container:Sprite = new Sprite();
+			container.x = 5;
+			container.y = 7;
+			addChild(container);
+
+			var first:Shape = makeBox(20, 10);
+			first.x = 10;
+			first.y = 40;
+			container.addChild(first);
+
+			var second:Shape = makeBox(30, 30);
+			second.x = 100;
+			second.y = -20;
+			second.scaleX = 2;
+			container.addChild(second);
+
+			for each (var space:* in [container, this]) {
+				var union:Rectangle = first.getBounds(space).union(second.getBounds(space));
+				var bounds:Rectangle = container.getBounds(space);
+				trace("/// In " + (space == container ? "the container's" : "the root's") + " space");
+				trace(first.getBounds(space));
+				trace(second.getBounds(space));
+				trace(union);
+				trace(bounds);
+				trace(bounds.equals(union));
+			}
+
+			trace("/// An empty child doesn't affect the union");
+			container.addChild(new Shape());
+			trace(container.getBounds(container));
+		}
+
+		private function makeBox(width:Number, height:Number):Shape {
+			var shape:Shape = new Shape();
+			shape.graphics.beginFill(0xFF0000);
+			shape.graphics.drawRect(0, 0, width, height);
+			shape.graphics.endFill();
+			return shape;
+		}
+	}
+}
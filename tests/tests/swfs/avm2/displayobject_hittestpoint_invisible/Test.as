@@ -0,0 +1,47 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var parentSprite:Sprite = new Sprite();
+			var sprite:Sprite = new Sprite();
+			sprite.graphics.beginFill(0xFF0000);
+			sprite.graphics.drawRect(0, 0, 100, 100);
+			sprite.graphics.endFill();
+			var child:Shape = new Shape();
+			child.graphics.beginFill(0x00FF00);
+			child.graphics.drawRect(200, 0, 50, 50);
+			child.graphics.endFill();
+			sprite.addChild(child);
+			parentSprite.addChild(sprite);
+			addChild(parentSprite);
+
+			trace("/// All visible");
+			test(parentSprite, sprite, child);
+
+			sprite.visible = false;
+			trace("/// sprite.visible = false");
+			test(parentSprite, sprite, child);
+
+			sprite.visible = true;
+			child.visible = false;
+			trace("/// child.visible = false");
+			test(parentSprite, sprite, child);
+
+			child.visible = true;
+			parentSprite.visible = false;
+			trace("/// parentSprite.visible = false");
+			test(parentSprite, sprite, child);
+		}
+
+		private function test(parentSprite:Sprite, sprite:Sprite, child:Shape):void {
+			trace("parentSprite.hitTestPoint(50, 50, true): " + parentSprite.hitTestPoint(50, 50, true));
+			trace("sprite.hitTestPoint(50, 50, true): " + sprite.hitTestPoint(50, 50, true));
+			trace("sprite.hitTestPoint(225, 25, true): " + sprite.hitTestPoint(225, 25, true));
+			trace("child.hitTestPoint(225, 25, true): " + child.hitTestPoint(225, 25, true));
+			trace("sprite.hitTestPoint(150, 25, true): " + sprite.hitTestPoint(150, 25, true));
+		}
+	}
+}
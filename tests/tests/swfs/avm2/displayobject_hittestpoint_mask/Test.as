@@ -0,0 +1,92 @@
+package {
+	import flash.display.DisplayObject;
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var big:Sprite = new Sprite();
+			big.graphics.beginFill(0xFF0000);
+			big.graphics.drawRect(0, 0, 200, 200);
+			big.graphics.endFill();
+			addChild(big);
+
+			var circle:Sprite = new Sprite();
+			circle.graphics.beginFill(0x00FF00);
+			circle.graphics.drawCircle(50, 50, 10);
+			circle.graphics.endFill();
+			addChild(circle);
+
+			trace("// big.mask");
+			trace(big.mask);
+			big.mask = circle;
+			trace("// big.mask = circle");
+			trace("big.mask === circle: " + (big.mask === circle));
+			test(big);
+
+			big.mask = null;
+			trace("// big.mask = null");
+			trace(big.mask);
+			test(big);
+
+			var shape:Shape = new Shape();
+			shape.graphics.beginFill(0x0000FF);
+			shape.graphics.drawRect(0, 0, 200, 200);
+			shape.graphics.endFill();
+			addChild(shape);
+
+			var circleShape:Shape = new Shape();
+			circleShape.graphics.beginFill(0x00FF00);
+			circleShape.graphics.drawCircle(50, 50, 10);
+			circleShape.graphics.endFill();
+			addChild(circleShape);
+
+			shape.mask = circleShape;
+			trace("// shape.mask = circleShape");
+			test(shape);
+
+			var clip:MovieClip = new MovieClip();
+			clip.graphics.beginFill(0xFF00FF);
+			clip.graphics.drawRect(0, 0, 200, 200);
+			clip.graphics.endFill();
+			addChild(clip);
+
+			var circleClip:MovieClip = new MovieClip();
+			circleClip.graphics.beginFill(0x00FF00);
+			circleClip.graphics.drawCircle(50, 50, 10);
+			circleClip.graphics.endFill();
+			addChild(circleClip);
+
+			clip.mask = circleClip;
+			trace("// clip.mask = circleClip");
+			test(clip);
+
+			var holder:MovieClip = new MovieClip();
+			var content:Shape = new Shape();
+			content.graphics.beginFill(0xFFFF00);
+			content.graphics.drawRect(0, 0, 200, 200);
+			content.graphics.endFill();
+			holder.addChild(content);
+			addChild(holder);
+
+			var holderMask:Shape = new Shape();
+			holderMask.graphics.beginFill(0x00FF00);
+			holderMask.graphics.drawCircle(50, 50, 10);
+			holderMask.graphics.endFill();
+			addChild(holderMask);
+
+			holder.mask = holderMask;
+			trace("// holder.mask = holderMask, with the art in a child Shape");
+			test(holder);
+		}
+
+		private function test(obj:DisplayObject):void {
+			var points:Array = [[50, 50], [150, 150], [59, 59]];
+			for each (var point:Array in points) {
+				trace("hitTestPoint(" + point[0] + ", " + point[1] + ", true): " + obj.hitTestPoint(point[0], point[1], true));
+				trace("hitTestPoint(" + point[0] + ", " + point[1] + ", false): " + obj.hitTestPoint(point[0], point[1], false));
+			}
+		}
+	}
+}
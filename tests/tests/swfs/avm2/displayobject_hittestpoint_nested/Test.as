@@ -0,0 +1,46 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var outer:Sprite = new Sprite();
+			var middle:Sprite = new Sprite();
+			var inner:Sprite = new Sprite();
+			outer.x = 100;
+			outer.y = 50;
+			middle.x = 20;
+			middle.y = 30;
+			middle.scaleX = 2;
+			middle.scaleY = 2;
+			inner.x = 5;
+			inner.y = 5;
+			inner.graphics.beginFill(0xFF0000);
+			inner.graphics.drawRect(0, 0, 10, 10);
+			inner.graphics.endFill();
+
+			addChild(outer);
+			outer.addChild(middle);
+			middle.addChild(inner);
+
+			// inner covers (130, 90) to (150, 110) in global space.
+			test(inner, 140, 100);
+			test(inner, 131, 91);
+			test(inner, 149, 109);
+			test(inner, 5, 5);
+			test(inner, 10, 10);
+			test(inner, 125, 100);
+			test(inner, 140, 115);
+			test(inner, 160, 100);
+			test(middle, 140, 100);
+			test(outer, 140, 100);
+			test(outer, 100, 50);
+		}
+
+		private function test(target:Sprite, x:Number, y:Number):void {
+			trace("hitTestPoint(" + x + ", " + y + "):",
+				target.hitTestPoint(x, y),
+				target.hitTestPoint(x, y, true));
+		}
+	}
+}
@@ -0,0 +1,51 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.events.Event;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var staged:Sprite = new Sprite();
+			var outer:Sprite = new Sprite();
+			var inner:Sprite = new Sprite();
+			staged.name = "staged";
+			outer.name = "outer";
+			inner.name = "inner";
+
+			addChild(staged);
+			outer.addChild(inner);
+
+			listen(staged);
+			listen(outer);
+			listen(inner);
+
+			trace("/// staged.addChild(outer)");
+			staged.addChild(outer);
+			trace("");
+
+			trace("/// staged.removeChild(outer)");
+			staged.removeChild(outer);
+			trace("");
+
+			trace("/// outer.removeChild(inner) while detached");
+			outer.removeChild(inner);
+			trace("");
+
+			trace("/// staged.addChild(inner)");
+			staged.addChild(inner);
+			trace("");
+
+			trace("/// removeChild(staged)");
+			removeChild(staged);
+		}
+
+		private function listen(target:Sprite):void {
+			var types:Array = [Event.ADDED, Event.ADDED_TO_STAGE, Event.REMOVED, Event.REMOVED_FROM_STAGE];
+			for each (var type:String in types) {
+				target.addEventListener(type, function(e:Event):void {
+					trace(e.currentTarget.name, e.type, "target=" + e.target.name, "phase=" + e.eventPhase);
+				});
+			}
+		}
+	}
+}
@@ -0,0 +1,86 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.Matrix3D;
+	import flash.geom.PerspectiveProjection;
+	import flash.geom.Point;
+	import flash.geom.Vector3D;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			sprite.x = 100;
+			sprite.y = 50;
+			sprite.scaleX = 2;
+			addChild(sprite);
+
+			trace("/// globalToLocal3D");
+			var local:Vector3D = sprite.globalToLocal3D(new Point(150, 70));
+			trace(local);
+			trace(sprite.globalToLocal3D(new Point(100, 50)));
+			trace("");
+
+			trace("/// local3DToGlobal with z = 0");
+			trace(sprite.local3DToGlobal(local));
+			trace(sprite.local3DToGlobal(new Vector3D(0, 0, 0)));
+			trace("");
+
+			trace("/// local3DToGlobal with the default projection");
+			trace(round(sprite.local3DToGlobal(new Vector3D(25, 20, 100))));
+			trace(round(sprite.local3DToGlobal(new Vector3D(25, 20, -100))));
+			trace("");
+
+			trace("/// local3DToGlobal with a custom projection");
+			var projection:PerspectiveProjection = new PerspectiveProjection();
+			projection.fieldOfView = 90;
+			projection.projectionCenter = new Point(0, 0);
+			sprite.transform.perspectiveProjection = projection;
+			trace(round(sprite.local3DToGlobal(new Vector3D(25, 20, 275))));
+			trace(round(sprite.local3DToGlobal(new Vector3D(25, 20, 0))));
+
+			trace("/// Children use their parent's projection");
+			var child:Sprite = new Sprite();
+			sprite.addChild(child);
+			trace(round(child.local3DToGlobal(new Vector3D(25, 20, 275))));
+			trace("");
+
+			trace("/// A new projection matches the default one");
+			sprite.transform.perspectiveProjection = new PerspectiveProjection();
+			trace(round(sprite.local3DToGlobal(new Vector3D(25, 20, 100))));
+			trace(round(sprite.local3DToGlobal(new Vector3D(25, 20, -100))));
+			trace("");
+
+			trace("/// A 3D object in a container with a projection");
+			var container:Sprite = new Sprite();
+			container.x = 50;
+			container.y = 40;
+			var containerProjection:PerspectiveProjection = new PerspectiveProjection();
+			containerProjection.fieldOfView = 90;
+			containerProjection.projectionCenter = new Point(0, 0);
+			container.transform.perspectiveProjection = containerProjection;
+			addChild(container);
+			var object:Sprite = new Sprite();
+			object.transform.matrix3D = new Matrix3D(Vector.<Number>([
+				0.6, 0, 0.8, 0,
+				0, 1, 0, 0,
+				-0.8, 0, 0.6, 0,
+				10, 20, 100, 1
+			]));
+			container.addChild(object);
+			trace(round(object.local3DToGlobal(new Vector3D(0, 0, 0))));
+			trace(round(object.local3DToGlobal(new Vector3D(100, 0, 0))));
+			trace(round(object.local3DToGlobal(new Vector3D(0, 100, 0))));
+			trace(round(object.local3DToGlobal(new Vector3D(100, 50, -20))));
+			var global:Point = object.local3DToGlobal(new Vector3D(40, 30, 0));
+			trace(roundVector(object.globalToLocal3D(global)));
+		}
+
+		private function round(point:Point):Point {
+			return new Point(Math.round(point.x * 100) / 100, Math.round(point.y * 100) / 100);
+		}
+
+		private function roundVector(vector:Vector3D):Vector3D {
+			return new Vector3D(Math.round(vector.x * 100) / 100, Math.round(vector.y * 100) / 100, vector.z);
+		}
+	}
+}
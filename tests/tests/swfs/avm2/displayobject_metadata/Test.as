@@ -0,0 +1,33 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.display.Sprite;
+
+var sprite:Sprite = new Sprite();
+
+trace("/// metaData (unset)");
+trace(sprite.metaData);
+trace("");
+
+trace("/// metaData = {label: \"OK\", enabled: true}");
+var data:Object = {label: "OK", enabled: true};
+sprite.metaData = data;
+trace("same object:", sprite.metaData === data);
+trace("label:", sprite.metaData.label);
+trace("enabled:", sprite.metaData.enabled);
+trace("");
+
+trace("/// metaData.label = \"Cancel\"");
+sprite.metaData.label = "Cancel";
+trace("label:", data.label);
+trace("");
+
+trace("/// metaData = null");
+sprite.metaData = null;
+trace(sprite.metaData);
@@ -0,0 +1,39 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.events.Event;
+
+	public class Test extends MovieClip {
+		private var parentSprite:Sprite = new Sprite();
+		private var sprite:Sprite = new Sprite();
+		private var moves:int = 0;
+
+		public function Test() {
+			// The sprite's origin is at (100, 50) on the stage, and its local x axis points down.
+			parentSprite.x = 20;
+			addChild(parentSprite);
+			sprite.x = 80;
+			sprite.y = 50;
+			sprite.rotation = 90;
+			parentSprite.addChild(sprite);
+
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		private function onEnterFrame(e:Event):void {
+			// Wait for the injected mouse moves along the stage's x axis.
+			if (stage.mouseX < 100) {
+				return;
+			}
+
+			trace("/// Mouse at stage (" + stage.mouseX + ", " + stage.mouseY + ")");
+			trace("parent mouse:", parentSprite.mouseX, parentSprite.mouseY);
+			trace("sprite mouse:", sprite.mouseX, sprite.mouseY);
+
+			moves++;
+			if (moves == 3) {
+				removeEventListener(Event.ENTER_FRAME, onEnterFrame);
+			}
+		}
+	}
+}
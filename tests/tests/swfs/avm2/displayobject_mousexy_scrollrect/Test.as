@@ -0,0 +1,57 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.geom.Rectangle;
+
+	public class Test extends MovieClip {
+		private var sprite:Sprite = new Sprite();
+		private var tick:int = 0;
+
+		public function Test() {
+			sprite.x = 50;
+			sprite.y = 50;
+			sprite.graphics.beginFill(0xFF0000);
+			sprite.graphics.drawRect(0, 0, 200, 200);
+			sprite.graphics.endFill();
+			addChild(sprite);
+
+			sprite.scrollRect = new Rectangle(10, 20, 100, 100);
+
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		private function onEnterFrame(e:Event):void {
+			// Wait for the injected mouse move to (100, 100).
+			if (tick == 0 && stage.mouseX != 100) {
+				return;
+			}
+
+			tick++;
+			if (tick == 1) {
+				trace("/// scrollRect (10, 20) applied");
+				traceMouse();
+
+				sprite.scrollRect = new Rectangle(30, 40, 100, 100);
+				trace("/// scrollRect (30, 40) set but not yet rendered");
+				traceMouse();
+			} else if (tick == 2) {
+				trace("/// scrollRect (30, 40) applied");
+				traceMouse();
+
+				sprite.scrollRect = null;
+				trace("/// scrollRect removed but not yet rendered");
+				traceMouse();
+			} else if (tick == 3) {
+				trace("/// scrollRect removal applied");
+				traceMouse();
+				removeEventListener(Event.ENTER_FRAME, onEnterFrame);
+			}
+		}
+
+		private function traceMouse():void {
+			trace("stage mouse:", stage.mouseX, stage.mouseY);
+			trace("sprite mouse:", sprite.mouseX, sprite.mouseY);
+		}
+	}
+}
@@ -0,0 +1,42 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var container:Sprite = new Sprite();
+			var a:Sprite = new Sprite();
+			var b:Sprite = new Sprite();
+			a.name = "a";
+			b.name = "b";
+			container.addChild(a);
+			container.addChild(b);
+
+			trace("/// Rename a child");
+			a.name = "renamed";
+			trace("a.name:", a.name);
+			trace("getChildByName(\"renamed\") === a:", container.getChildByName("renamed") === a);
+			trace("getChildByName(\"a\"):", container.getChildByName("a"));
+			trace("");
+
+			trace("/// Rename to a sibling's name");
+			b.name = "renamed";
+			trace("getChildByName(\"renamed\") === a:", container.getChildByName("renamed") === a);
+			trace("getChildByName(\"b\"):", container.getChildByName("b"));
+			trace("");
+
+			trace("/// Reorder the colliding children");
+			container.setChildIndex(b, 0);
+			trace("getChildByName(\"renamed\") === b:", container.getChildByName("renamed") === b);
+			trace("");
+
+			trace("/// Rename while detached, then add");
+			var c:Sprite = new Sprite();
+			c.name = "c";
+			c.name = "late";
+			container.addChild(c);
+			trace("getChildByName(\"late\") === c:", container.getChildByName("late") === c);
+			trace("getChildByName(\"c\"):", container.getChildByName("c"));
+		}
+	}
+}
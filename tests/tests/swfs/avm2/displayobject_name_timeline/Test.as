@@ -0,0 +1,34 @@
+package {
+	import flash.display.DisplayObject;
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.errors.IllegalOperationError;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("/// Script-created Sprite");
+			var sprite:Sprite = new Sprite();
+			trace(sprite.name.substr(0, 8));
+			rename(sprite, "renamed");
+			addChild(sprite);
+			rename(sprite, "renamedAgain");
+
+			trace("/// Timeline-placed clip");
+			var placed:DisplayObject = getChildByName("placedClip");
+			trace(placed != null);
+			rename(placed, "renamed");
+		}
+
+		private function rename(target:DisplayObject, newName:String):void {
+			try {
+				target.name = newName;
+				trace("name is now " + target.name);
+			} catch (e) {
+				trace("caught: " + e.message);
+				trace("is IllegalOperationError: " + (e is IllegalOperationError));
+				trace("errorID: " + e.errorID);
+				trace("name is still " + target.name);
+			}
+		}
+	}
+}
@@ -0,0 +1,37 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			var inner:Sprite = new Sprite();
+			sprite.addChild(inner);
+
+			trace("/// Never added");
+			trace("sprite.root === null:", sprite.root === null);
+			trace("inner.root === null:", inner.root === null);
+			trace("");
+
+			trace("/// Added to the root");
+			addChild(sprite);
+			trace("sprite.root === this:", sprite.root === this);
+			trace("inner.root === this:", inner.root === this);
+			trace("this.root === this:", this.root === this);
+			trace("");
+
+			trace("/// Removed again");
+			removeChild(sprite);
+			trace("sprite.root === null:", sprite.root === null);
+			trace("inner.root === null:", inner.root === null);
+			trace("");
+
+			trace("/// Moved to another detached container");
+			var other:Sprite = new Sprite();
+			other.addChild(inner);
+			trace("inner.root === null:", inner.root === null);
+			addChild(other);
+			trace("inner.root === this:", inner.root === this);
+		}
+	}
+}
@@ -0,0 +1,18 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			addChild(sprite);
+
+			var values:Array = [0, 10.00001, 10.5, -10.25, 45.5, 90, 179.99, 180, 190, -190, 370.5, -370.5, 540, 720, 720.125];
+			for each (var value:Number in values) {
+				sprite.rotation = value;
+				trace("/// sprite.rotation = " + value);
+				trace(sprite.rotation);
+			}
+		}
+	}
+}
@@ -0,0 +1,31 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var shape:Shape = new Shape();
+			shape.graphics.beginFill(0xFF0000);
+			shape.graphics.drawRect(0, 0, 10, 10);
+			shape.graphics.endFill();
+			shape.scaleX = 2;
+			shape.scaleY = 3;
+
+			var values:Array = [NaN, Infinity, -Infinity, undefined];
+			for each (var value:* in values) {
+				shape.scaleX = value;
+				shape.scaleY = value;
+				trace("/// scale = " + value);
+				trace("scaleX:", shape.scaleX, "scaleY:", shape.scaleY);
+				trace("width:", shape.width, "height:", shape.height);
+				trace("matrix:", shape.transform.matrix);
+			}
+
+			trace("/// scale = 0.5");
+			shape.scaleX = 0.5;
+			shape.scaleY = 0.5;
+			trace("scaleX:", shape.scaleX, "scaleY:", shape.scaleY);
+			trace("width:", shape.width, "height:", shape.height);
+		}
+	}
+}
@@ -0,0 +1,44 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.Matrix;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var scaled:Sprite = new Sprite();
+			scaled.transform.matrix = new Matrix(2, 0, 0, 3, 0, 0);
+
+			trace("/// Scaled matrix, read twice");
+			dump(scaled);
+			dump(scaled);
+
+			trace("/// scaled.scaleX = 4");
+			scaled.scaleX = 4;
+			dump(scaled);
+			trace("matrix:", scaled.transform.matrix);
+
+			var skewed:Sprite = new Sprite();
+			skewed.transform.matrix = new Matrix(1, 0, 1, 1, 0, 0);
+
+			trace("/// Skewed matrix");
+			dump(skewed);
+
+			trace("/// skewed.scaleX = 2");
+			skewed.scaleX = 2;
+			dump(skewed);
+			trace("matrix:", skewed.transform.matrix);
+
+			trace("/// skewed.rotation = 0");
+			skewed.rotation = 0;
+			dump(skewed);
+
+			trace("/// Identity matrix after setters");
+			skewed.transform.matrix = new Matrix();
+			dump(skewed);
+		}
+
+		private function dump(target:Sprite):void {
+			trace("scaleX:", target.scaleX, "scaleY:", target.scaleY, "rotation:", target.rotation);
+		}
+	}
+}
@@ -0,0 +1,48 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.events.Event;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var container:Sprite = new Sprite();
+			var sprite:Sprite = new Sprite();
+			listen(container, "container");
+			listen(sprite, "sprite");
+
+			trace("/// Never added");
+			trace("sprite.stage === null:", sprite.stage === null);
+			trace("");
+
+			trace("/// Added to a detached container");
+			container.addChild(sprite);
+			trace("sprite.stage === null:", sprite.stage === null);
+			trace("");
+
+			trace("/// Container added to the stage");
+			addChild(container);
+			trace("sprite.stage === stage:", sprite.stage === stage);
+			trace("container.stage === stage:", container.stage === stage);
+			trace("");
+
+			trace("/// Container removed from the stage");
+			removeChild(container);
+			trace("sprite.stage === null:", sprite.stage === null);
+			trace("container.stage === null:", container.stage === null);
+		}
+
+		private function listen(target:Sprite, name:String):void {
+			target.addEventListener(Event.ADDED, function(e:Event):void {
+				if (e.target === target) {
+					trace(name, "added, stage === null:", target.stage === null);
+				}
+			});
+			target.addEventListener(Event.ADDED_TO_STAGE, function(e:Event):void {
+				trace(name, "addedToStage, stage === null:", target.stage === null);
+			});
+			target.addEventListener(Event.REMOVED_FROM_STAGE, function(e:Event):void {
+				trace(name, "removedFromStage, stage === null:", target.stage === null);
+			});
+		}
+	}
+}
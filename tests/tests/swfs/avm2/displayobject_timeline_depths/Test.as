@@ -0,0 +1,41 @@
+package {
+	import flash.display.DisplayObject;
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		// Placed on frame 1 in this order, at depths 5, 1, 2 (clipping up to 4) and 3.
+		public var five:MovieClip;
+		public var one:MovieClip;
+		public var mask_layer:MovieClip;
+		public var three:MovieClip;
+
+		public function Test() {
+			trace("/// Timeline children in depth order");
+			dump();
+
+			trace("/// getChildIndex");
+			trace("one:", getChildIndex(one));
+			trace("mask_layer:", getChildIndex(mask_layer));
+			trace("three:", getChildIndex(three));
+			trace("five:", getChildIndex(five));
+
+			trace("/// addChild appends after the timeline children");
+			var added:Sprite = new Sprite();
+			added.name = "added";
+			addChild(added);
+			dump();
+
+			trace("/// removeChild(three)");
+			removeChild(three);
+			dump();
+		}
+
+		private function dump():void {
+			for (var i:int = 0; i < numChildren; i++) {
+				var child:DisplayObject = getChildAt(i);
+				trace(i, child.name);
+			}
+		}
+	}
+}
@@ -0,0 +1,45 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.geom.Matrix;
+	import flash.geom.Point;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var shape:Shape = new Shape();
+			shape.graphics.beginFill(0xFF0000);
+			shape.graphics.drawRect(0, 0, 50, 20);
+			shape.graphics.endFill();
+			addChild(shape);
+
+			trace("/// Assigning a matrix with scaleX = 0");
+			shape.transform.matrix = new Matrix(0, 0, 0, 1, 100, 50);
+			trace(shape.transform.matrix);
+			trace(shape.scaleX, shape.scaleY);
+			trace(shape.x, shape.y);
+			trace("");
+
+			trace("/// The object collapses to nothing");
+			trace(shape.width, shape.height);
+			trace(shape.getBounds(this));
+			trace(shape.hitTestPoint(110, 60, true));
+			trace("");
+
+			trace("/// Global points all map to the local origin");
+			trace(shape.globalToLocal3D(new Point(150, 70)));
+			trace(shape.globalToLocal3D(new Point(0, 0)));
+			trace(shape.mouseX, shape.mouseY);
+			trace("");
+
+			trace("/// Assigning a matrix with a determinant of 0");
+			shape.transform.matrix = new Matrix(1, 1, 1, 1, 100, 50);
+			trace(shape.globalToLocal3D(new Point(150, 70)));
+			trace("");
+
+			trace("/// Restoring an invertible matrix");
+			shape.transform.matrix = new Matrix(2, 0, 0, 1, 100, 50);
+			trace(shape.width, shape.height);
+			trace(shape.globalToLocal3D(new Point(150, 70)));
+		}
+	}
+}
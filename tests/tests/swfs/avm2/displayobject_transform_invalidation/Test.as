@@ -0,0 +1,49 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.geom.ColorTransform;
+	import flash.geom.Matrix;
+	import flash.geom.Transform;
+
+	public class Test extends MovieClip {
+		private var sprite:Sprite = new Sprite();
+		private var frame:int = 0;
+
+		public function Test() {
+			sprite.graphics.beginFill(0xFF0000);
+			sprite.graphics.drawRect(0, 0, 20, 20);
+			sprite.graphics.endFill();
+			addChild(sprite);
+
+			// Populate the cached bounds before changing the transform.
+			trace("/// Before");
+			dump();
+
+			var other:Sprite = new Sprite();
+			other.transform.matrix = new Matrix(2, 0, 0, 2, 100, 50);
+			other.transform.colorTransform = new ColorTransform(0, 1, 1, 1, 255);
+			sprite.transform = other.transform;
+			trace("/// Immediately after assigning transform");
+			dump();
+
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		private function onEnterFrame(e:Event):void {
+			frame++;
+			if (frame == 2) {
+				trace("/// After rendering");
+				dump();
+				removeEventListener(Event.ENTER_FRAME, onEnterFrame);
+			}
+		}
+
+		private function dump():void {
+			trace(sprite.getBounds(this));
+			trace(sprite.hitTestPoint(10, 10, true));
+			trace(sprite.hitTestPoint(130, 80, true));
+			trace(sprite.transform.colorTransform.redOffset);
+		}
+	}
+}
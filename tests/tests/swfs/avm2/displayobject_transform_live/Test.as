@@ -0,0 +1,49 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.ColorTransform;
+	import flash.geom.Matrix;
+	import flash.geom.Transform;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var s:Sprite = new Sprite();
+			var first:Transform = s.transform;
+			var second:Transform = s.transform;
+			trace("// first === second");
+			trace(first === second);
+
+			var m:Matrix = first.matrix;
+			m.tx = 50;
+			m.ty = 25;
+			trace("// after mutating first.matrix without reassigning");
+			trace("s.x: " + s.x + ", s.y: " + s.y);
+			trace("second.matrix: " + second.matrix);
+
+			first.matrix = m;
+			trace("// after first.matrix = m");
+			trace("s.x: " + s.x + ", s.y: " + s.y);
+			trace("second.matrix: " + second.matrix);
+
+			s.x = 5;
+			s.scaleX = 2;
+			trace("// after s.x = 5, s.scaleX = 2");
+			trace("first.matrix: " + first.matrix);
+			trace("second.matrix: " + second.matrix);
+
+			second.colorTransform = new ColorTransform(0.5, 1, 1, 1, 10, 0, 0, 0);
+			trace("// after second.colorTransform = ...");
+			trace("first.colorTransform: " + first.colorTransform);
+
+			var other:Sprite = new Sprite();
+			other.transform = first;
+			trace("// after other.transform = first");
+			trace("other.transform.matrix: " + other.transform.matrix);
+			trace("other.transform.colorTransform: " + other.transform.colorTransform);
+			other.x = 100;
+			trace("// after other.x = 100");
+			trace("s.x: " + s.x);
+			trace("first.matrix: " + first.matrix);
+		}
+	}
+}
@@ -0,0 +1,34 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.ColorTransform;
+	import flash.geom.Matrix;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			sprite.transform.colorTransform = new ColorTransform(0.5, 1, 1, 0.75, 10, 20, 30, 0);
+
+			trace("/// Assign only transform.matrix");
+			sprite.transform.matrix = new Matrix(2, 0, 0, 3, 15, 25);
+			trace("matrix:", sprite.transform.matrix);
+			trace("colorTransform:", sprite.transform.colorTransform);
+			trace("scaleX:", sprite.scaleX, "scaleY:", sprite.scaleY, "x:", sprite.x, "y:", sprite.y);
+			trace("alpha:", sprite.alpha);
+			trace("");
+
+			trace("/// Assign only transform.colorTransform");
+			sprite.transform.colorTransform = new ColorTransform(1, 1, 1, 1, 0, 0, 255, 0);
+			trace("matrix:", sprite.transform.matrix);
+			trace("colorTransform:", sprite.transform.colorTransform);
+			trace("");
+
+			trace("/// Assign a whole transform");
+			var other:Sprite = new Sprite();
+			other.transform.matrix = new Matrix(1, 0, 0, 1, 5, 5);
+			sprite.transform = other.transform;
+			trace("matrix:", sprite.transform.matrix);
+			trace("colorTransform:", sprite.transform.colorTransform);
+		}
+	}
+}
@@ -0,0 +1,34 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var s:Sprite = new Sprite();
+			s.x = 10;
+			s.alpha = 0.5;
+
+			try {
+				s.transform = null;
+				trace("no error");
+			} catch (e) {
+				trace("caught: " + e);
+			}
+			trace("x: " + s.x + ", alpha: " + s.alpha);
+
+			try {
+				s.transform = undefined;
+				trace("no error");
+			} catch (e) {
+				trace("caught: " + e);
+			}
+			trace("x: " + s.x + ", alpha: " + s.alpha);
+
+			var other:Sprite = new Sprite();
+			other.x = 25;
+			s.transform = other.transform;
+			trace("// after s.transform = other.transform");
+			trace("x: " + s.x + ", alpha: " + s.alpha);
+		}
+	}
+}
@@ -0,0 +1,40 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.ColorTransform;
+	import flash.geom.Matrix3D;
+	import flash.geom.Transform;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			addChild(sprite);
+			sprite.x = 10;
+			sprite.scaleX = -1;
+			sprite.rotation = 30;
+
+			trace("/// Reassigning an object's own transform");
+			sprite.transform = sprite.transform;
+			dump(sprite);
+
+			trace("/// Changing only the colorTransform keeps the matrix components");
+			var transform:Transform = sprite.transform;
+			transform.colorTransform = new ColorTransform(1, 1, 1, 0.5);
+			sprite.transform = transform;
+			dump(sprite);
+
+			trace("/// A 3D object's transform has no matrix, so only its colorTransform is copied");
+			var other:Sprite = new Sprite();
+			other.transform.matrix3D = new Matrix3D();
+			other.transform.colorTransform = new ColorTransform(1, 1, 1, 1, 64);
+			trace(other.transform.matrix);
+			sprite.transform = other.transform;
+			dump(sprite);
+		}
+
+		private function dump(sprite:Sprite):void {
+			trace("x: " + sprite.x + ", scaleX: " + sprite.scaleX + ", rotation: " + Math.round(sprite.rotation));
+			trace("alpha: " + sprite.alpha + ", redOffset: " + sprite.transform.colorTransform.redOffset);
+		}
+	}
+}
@@ -0,0 +1,51 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.geom.ColorTransform;
+	import flash.geom.Transform;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var source:Shape = square();
+			var target:Shape = square();
+			addChild(source);
+			addChild(target);
+
+			trace("/// Tint the source through its Transform");
+			var tint:ColorTransform = new ColorTransform();
+			tint.color = 0xFF8000;
+			var holder:Transform = source.transform;
+			holder.colorTransform = tint;
+			source.x = 30;
+			trace(source.transform.colorTransform);
+			trace("");
+
+			trace("/// Assign the Transform to the target");
+			target.transform = holder;
+			trace(target.transform.colorTransform);
+			trace("color:", target.transform.colorTransform.color.toString(16));
+			trace("x:", target.x);
+			trace("");
+
+			trace("/// Changing a copy of the colorTransform has no effect");
+			target.transform.colorTransform.color = 0x0000FF;
+			trace("color:", target.transform.colorTransform.color.toString(16));
+			trace("");
+
+			trace("/// Reassigning the modified copy applies it");
+			var copy:ColorTransform = target.transform.colorTransform;
+			copy.color = 0x0000FF;
+			target.transform.colorTransform = copy;
+			trace("color:", target.transform.colorTransform.color.toString(16));
+			trace("source color:", source.transform.colorTransform.color.toString(16));
+		}
+
+		private function square():Shape {
+			var shape:Shape = new Shape();
+			shape.graphics.beginFill(0xFFFFFF);
+			shape.graphics.drawRect(0, 0, 20, 20);
+			shape.graphics.endFill();
+			return shape;
+		}
+	}
+}
@@ -0,0 +1,37 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			trace("/// New empty Sprite");
+			trace(sprite.width === 0);
+			trace(sprite.height === 0);
+
+			addChild(sprite);
+			sprite.x = 50;
+			sprite.y = 60;
+			sprite.rotation = 30;
+			sprite.scaleX = 3;
+			trace("/// Moved, rotated and scaled on the stage");
+			trace(sprite.width === 0);
+			trace(sprite.height === 0);
+
+			sprite.addChild(new Shape());
+			sprite.addChild(new Sprite());
+			trace("/// With empty children");
+			trace(sprite.width === 0);
+			trace(sprite.height === 0);
+
+			sprite.graphics.beginFill(0xFF0000);
+			sprite.graphics.drawRect(0, 0, 10, 10);
+			sprite.graphics.endFill();
+			sprite.graphics.clear();
+			trace("/// After clearing its graphics");
+			trace(sprite.width === 0);
+			trace(sprite.height === 0);
+		}
+	}
+}
@@ -0,0 +1,16 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			var values:Array = [10, 10.04, 10.05, 10.06, 10.0999, 10.1, 0.01, 0.05, 123.456];
+			for each (var value:Number in values) {
+				sprite.x = value;
+				sprite.y = value;
+				trace("x = " + value + ":", sprite.x, "y = " + value + ":", sprite.y);
+			}
+		}
+	}
+}
@@ -0,0 +1,56 @@
+package {
+	import flash.display.MovieClip;
+	import flash.filters.BitmapFilterQuality;
+	import flash.filters.BlurFilter;
+	import flash.filters.DropShadowFilter;
+	import flash.filters.GlowFilter;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("/// BitmapFilterQuality");
+			trace(BitmapFilterQuality.LOW, BitmapFilterQuality.MEDIUM, BitmapFilterQuality.HIGH);
+			trace("");
+
+			var blur:BlurFilter = new BlurFilter();
+			trace("/// BlurFilter");
+			blur.quality = 10;
+			trace("quality = 10:", blur.quality);
+			blur.quality = 0;
+			trace("quality = 0:", blur.quality);
+			blur.quality = -5;
+			trace("quality = -5:", blur.quality);
+			blur.quality = BitmapFilterQuality.MEDIUM;
+			trace("quality = MEDIUM:", blur.quality);
+			blur.blurX = 300;
+			trace("blurX = 300:", blur.blurX);
+			blur.blurY = -1;
+			trace("blurY = -1:", blur.blurY);
+			blur.blurY = 12.5;
+			trace("blurY = 12.5:", blur.blurY);
+			trace("");
+
+			trace("/// Constructor arguments are clamped");
+			blur = new BlurFilter(1000, -1000, 99);
+			trace("blurX:", blur.blurX, "blurY:", blur.blurY, "quality:", blur.quality);
+			var glow:GlowFilter = new GlowFilter(0xFF0000, 1, 256, 255, 2, 0);
+			trace("blurX:", glow.blurX, "blurY:", glow.blurY, "quality:", glow.quality);
+			trace("");
+
+			var shadow:DropShadowFilter = new DropShadowFilter();
+			trace("/// DropShadowFilter");
+			shadow.quality = 10;
+			trace("quality = 10:", shadow.quality);
+			shadow.blurX = 300;
+			trace("blurX = 300:", shadow.blurX);
+			shadow.angle = 360;
+			trace("angle = 360:", shadow.angle);
+			shadow.angle = 405;
+			trace("angle = 405:", shadow.angle);
+			shadow.angle = -90;
+			trace("angle = -90:", shadow.angle);
+			shadow.angle = 720.5;
+			trace("angle = 720.5:", shadow.angle);
+			trace("clone:", DropShadowFilter(new DropShadowFilter(4, -45, 0, 1, 300, 4, 1, 7).clone()).angle);
+		}
+	}
+}
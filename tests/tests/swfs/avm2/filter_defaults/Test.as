@@ -0,0 +1,64 @@
+package {
+	import flash.display.MovieClip;
+	import flash.filters.BitmapFilter;
+	import flash.filters.BlurFilter;
+	import flash.filters.DropShadowFilter;
+	import flash.filters.GlowFilter;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("/// new BlurFilter()");
+			dumpBlur(new BlurFilter());
+			trace("/// new BlurFilter(1.5, 20, 3)");
+			dumpBlur(new BlurFilter(1.5, 20, 3));
+			trace("/// clone");
+			dumpBlur(BlurFilter(new BlurFilter(1.5, 20, 3).clone()));
+			trace("");
+
+			trace("/// new GlowFilter()");
+			dumpGlow(new GlowFilter());
+			trace("/// new GlowFilter(0x123456, 0.5, 2, 3, 4, 2, true, true)");
+			dumpGlow(new GlowFilter(0x123456, 0.5, 2, 3, 4, 2, true, true));
+			trace("/// clone");
+			dumpGlow(GlowFilter(new GlowFilter(0x123456, 0.5, 2, 3, 4, 2, true, true).clone()));
+			trace("");
+
+			trace("/// new DropShadowFilter()");
+			dumpDropShadow(new DropShadowFilter());
+			trace("/// new DropShadowFilter(10, 90, 0xFF00FF, 0.25, 8, 9, 3, 3, true, true, true)");
+			dumpDropShadow(new DropShadowFilter(10, 90, 0xFF00FF, 0.25, 8, 9, 3, 3, true, true, true));
+			trace("/// clone");
+			dumpDropShadow(DropShadowFilter(new DropShadowFilter(10, 90, 0xFF00FF, 0.25, 8, 9, 3, 3, true, true, true).clone()));
+			trace("");
+
+			trace("/// Coercion");
+			var shadow:DropShadowFilter = new DropShadowFilter();
+			shadow.color = -1;
+			shadow.quality = 2.7;
+			shadow.inner = 1;
+			shadow.distance = "5";
+			trace("color:", shadow.color);
+			trace("quality:", shadow.quality);
+			trace("inner:", shadow.inner);
+			trace("distance:", shadow.distance);
+			trace("shadow is BitmapFilter:", shadow is BitmapFilter);
+		}
+
+		private function dumpBlur(filter:BlurFilter):void {
+			trace("blurX:", filter.blurX, "blurY:", filter.blurY, "quality:", filter.quality);
+		}
+
+		private function dumpGlow(filter:GlowFilter):void {
+			trace("color:", filter.color.toString(16), "alpha:", filter.alpha);
+			trace("blurX:", filter.blurX, "blurY:", filter.blurY, "strength:", filter.strength, "quality:", filter.quality);
+			trace("inner:", filter.inner, "knockout:", filter.knockout);
+		}
+
+		private function dumpDropShadow(filter:DropShadowFilter):void {
+			trace("distance:", filter.distance, "angle:", filter.angle);
+			trace("color:", filter.color.toString(16), "alpha:", filter.alpha);
+			trace("blurX:", filter.blurX, "blurY:", filter.blurY, "strength:", filter.strength, "quality:", filter.quality);
+			trace("inner:", filter.inner, "knockout:", filter.knockout, "hideObject:", filter.hideObject);
+		}
+	}
+}
@@ -0,0 +1,54 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			// Two 100x100 squares rotated by 45 degrees, forming diamonds.
+			// Their axis-aligned bounds overlap in the region
+			// x = 29.3..70.7, y = 100..141.4, but the diamonds themselves don't touch.
+			var a:Shape = diamond(0, 0);
+			var b:Shape = diamond(100, 100);
+			var far:Shape = diamond(300, 0);
+
+			trace("/// a.hitTestObject(b)");
+			trace(a.hitTestObject(b));
+			trace(b.hitTestObject(a));
+			trace("");
+
+			trace("/// a.hitTestObject(far)");
+			trace(a.hitTestObject(far));
+			trace(far.hitTestObject(a));
+			trace("");
+
+			trace("/// a.hitTestObject(a)");
+			trace(a.hitTestObject(a));
+			trace("");
+
+			trace("/// point (50, 120), inside both bounding boxes but outside both shapes");
+			trace("a.hitTestPoint(50, 120, false):", a.hitTestPoint(50, 120, false));
+			trace("b.hitTestPoint(50, 120, false):", b.hitTestPoint(50, 120, false));
+			trace("a.hitTestPoint(50, 120, true):", a.hitTestPoint(50, 120, true));
+			trace("b.hitTestPoint(50, 120, true):", b.hitTestPoint(50, 120, true));
+			trace("");
+
+			trace("/// b unrotated and moved to (200, 0)");
+			b.rotation = 0;
+			b.x = 200;
+			b.y = 0;
+			trace(a.hitTestObject(b));
+		}
+
+		private function diamond(x:Number, y:Number):Shape {
+			var shape:Shape = new Shape();
+			shape.graphics.beginFill(0xFF0000);
+			shape.graphics.drawRect(0, 0, 100, 100);
+			shape.graphics.endFill();
+			shape.x = x;
+			shape.y = y;
+			shape.rotation = 45;
+			this.addChild(shape);
+			return shape;
+		}
+	}
+}
@@ -0,0 +1,15 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+
+	// Compiled into `child.swf`, which is loaded by `Test.as`.
+	public class Test extends MovieClip {
+		public var nested:Sprite;
+
+		public function Test() {
+			this.nested = new Sprite();
+			this.addChild(this.nested);
+			trace("child: nested.loaderInfo === this.loaderInfo: " + (this.nested.loaderInfo === this.loaderInfo));
+		}
+	}
+}
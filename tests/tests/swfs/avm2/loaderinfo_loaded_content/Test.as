@@ -0,0 +1,29 @@
+package {
+	import flash.display.Loader;
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.net.URLRequest;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var main:MovieClip = this;
+			var loader:Loader = new Loader();
+			this.addChild(loader);
+
+			loader.contentLoaderInfo.addEventListener(Event.INIT, function(e:Event):void {
+				var content:Object = loader.content;
+				trace("content.loaderInfo === loader.contentLoaderInfo: " + (content.loaderInfo === loader.contentLoaderInfo));
+				trace("content.loaderInfo !== main.loaderInfo: " + (content.loaderInfo !== main.loaderInfo));
+				trace("content.nested.loaderInfo === loader.contentLoaderInfo: " + (content.nested.loaderInfo === loader.contentLoaderInfo));
+				trace("loader.loaderInfo === main.loaderInfo: " + (loader.loaderInfo === main.loaderInfo));
+
+				var url:String = content.loaderInfo.url;
+				trace("content.loaderInfo.url ends with child.swf: " + (url.substr(url.length - 9) == "child.swf"));
+				url = main.loaderInfo.url;
+				trace("main.loaderInfo.url ends with test.swf: " + (url.substr(url.length - 8) == "test.swf"));
+			});
+
+			loader.load(new URLRequest("./child.swf"));
+		}
+	}
+}
@@ -0,0 +1,34 @@
+package {
+	import flash.display.DisplayObject;
+	import flash.display.LoaderInfo;
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var main:DisplayObject = stage.getChildAt(0);
+			var info:LoaderInfo = main.loaderInfo;
+			trace("/// stage.getChildAt(0).loaderInfo");
+			trace(main === this);
+			trace(info != null);
+			trace(info === loaderInfo);
+			trace(info === root.loaderInfo);
+			trace(info.content === main);
+
+			trace("/// url");
+			var url:String = info.url;
+			trace(url.substr(url.length - 8) == "test.swf");
+
+			trace("/// bytesTotal");
+			trace(info.bytesTotal > 0);
+			trace(info.bytesLoaded == info.bytesTotal);
+
+			trace("/// parameters");
+			trace(info.parameters != null);
+			var count:int = 0;
+			for (var key:String in info.parameters) {
+				count++;
+			}
+			trace(count);
+		}
+	}
+}
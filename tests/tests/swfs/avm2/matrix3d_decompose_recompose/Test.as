@@ -0,0 +1,68 @@
+package {
+	import flash.display.MovieClip;
+	import flash.geom.Matrix3D;
+	import flash.geom.Orientation3D;
+	import flash.geom.Vector3D;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("/// Decomposing a rotation about Z followed by a translation");
+			var m:Matrix3D = new Matrix3D();
+			m.appendRotation(30, Vector3D.Z_AXIS);
+			m.appendTranslation(10, 20, 30);
+			var components:Vector.<Vector3D> = m.decompose();
+			dump(components);
+
+			trace("/// Recomposing it gives back the original matrix");
+			var recomposed:Matrix3D = new Matrix3D();
+			trace(recomposed.recompose(components));
+			trace(sameRawData(m, recomposed));
+
+			trace("/// Round trip through recompose and decompose");
+			var original:Vector.<Vector3D> = new Vector.<Vector3D>();
+			original.push(new Vector3D(1, 2, 3), new Vector3D(radians(45), radians(-60), radians(120)), new Vector3D(2, 3, 4));
+			m.recompose(original, Orientation3D.EULER_ANGLES);
+			dump(m.decompose(Orientation3D.EULER_ANGLES));
+
+			trace("/// Mirrored matrices flip the Z scale");
+			original[2] = new Vector3D(1, 1, -2);
+			m.recompose(original);
+			dump(m.decompose());
+
+			trace("/// recompose fails with a zero scale and leaves the matrix alone");
+			var before:Matrix3D = m.clone();
+			original[2] = new Vector3D(1, 0, 1);
+			trace(m.recompose(original));
+			trace(sameRawData(m, before));
+		}
+
+		private function dump(components:Vector.<Vector3D>):void {
+			trace("translation: " + round(components[0].x) + ", " + round(components[0].y) + ", " + round(components[0].z));
+			trace("rotation (degrees): " + round(degrees(components[1].x)) + ", " + round(degrees(components[1].y)) + ", " + round(degrees(components[1].z)));
+			trace("scale: " + round(components[2].x) + ", " + round(components[2].y) + ", " + round(components[2].z));
+		}
+
+		private function sameRawData(a:Matrix3D, b:Matrix3D):Boolean {
+			var rawA:Vector.<Number> = a.rawData;
+			var rawB:Vector.<Number> = b.rawData;
+			for (var i:int = 0; i < 16; i++) {
+				if (Math.abs(rawA[i] - rawB[i]) > 1e-9) {
+					return false;
+				}
+			}
+			return true;
+		}
+
+		private function radians(degrees:Number):Number {
+			return degrees * Math.PI / 180;
+		}
+
+		private function degrees(radians:Number):Number {
+			return radians * 180 / Math.PI;
+		}
+
+		private function round(value:Number):Number {
+			return Math.round(value * 1000000) / 1000000;
+		}
+	}
+}
@@ -0,0 +1,71 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Matrix3D;
+import flash.geom.Vector3D;
+
+function round(n:Number):Number {
+	// Hide floating point noise such as cos(90deg) != 0
+	var r:Number = Math.round(n * 1000) / 1000;
+	return r == 0 ? 0 : r;
+}
+
+function show(v:Vector3D):String {
+	return "(" + round(v.x) + ", " + round(v.y) + ", " + round(v.z) + ")";
+}
+
+var m:Matrix3D;
+
+trace("/// appendRotation(90, Z_AXIS)");
+m = new Matrix3D();
+m.appendRotation(90, Vector3D.Z_AXIS);
+trace("(1, 0, 0) ->", show(m.transformVector(new Vector3D(1, 0, 0))));
+trace("(0, 1, 0) ->", show(m.transformVector(new Vector3D(0, 1, 0))));
+trace("(0, 0, 1) ->", show(m.transformVector(new Vector3D(0, 0, 1))));
+trace("");
+
+trace("/// appendRotation(90, X_AXIS)");
+m = new Matrix3D();
+m.appendRotation(90, Vector3D.X_AXIS);
+trace("(0, 1, 0) ->", show(m.transformVector(new Vector3D(0, 1, 0))));
+trace("");
+
+trace("/// appendRotation(90, Y_AXIS)");
+m = new Matrix3D();
+m.appendRotation(90, Vector3D.Y_AXIS);
+trace("(0, 0, 1) ->", show(m.transformVector(new Vector3D(0, 0, 1))));
+trace("");
+
+trace("/// appendRotation(90, non-normalized Z axis)");
+m = new Matrix3D();
+m.appendRotation(90, new Vector3D(0, 0, 5));
+trace("(1, 0, 0) ->", show(m.transformVector(new Vector3D(1, 0, 0))));
+trace("");
+
+trace("/// appendRotation(90, Z_AXIS, pivot (1, 1, 0))");
+m = new Matrix3D();
+m.appendRotation(90, Vector3D.Z_AXIS, new Vector3D(1, 1, 0));
+trace("(2, 1, 0) ->", show(m.transformVector(new Vector3D(2, 1, 0))));
+trace("(1, 1, 0) ->", show(m.transformVector(new Vector3D(1, 1, 0))));
+trace("");
+
+trace("/// appendTranslation(10, 0, 0) then appendRotation(90, Z_AXIS)");
+m = new Matrix3D();
+m.appendTranslation(10, 0, 0);
+m.appendRotation(90, Vector3D.Z_AXIS);
+trace("(0, 0, 0) ->", show(m.transformVector(new Vector3D(0, 0, 0))));
+trace("(1, 0, 0) ->", show(m.transformVector(new Vector3D(1, 0, 0))));
+trace("");
+
+trace("/// appendTranslation(10, 0, 0) then prependRotation(90, Z_AXIS)");
+m = new Matrix3D();
+m.appendTranslation(10, 0, 0);
+m.prependRotation(90, Vector3D.Z_AXIS);
+trace("(0, 0, 0) ->", show(m.transformVector(new Vector3D(0, 0, 0))));
+trace("(1, 0, 0) ->", show(m.transformVector(new Vector3D(1, 0, 0))));
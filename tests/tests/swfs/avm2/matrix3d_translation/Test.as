@@ -0,0 +1,64 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Matrix3D;
+
+function scaled():Matrix3D {
+	// Uniform scale by 2, no translation
+	return new Matrix3D(Vector.<Number>([
+		2, 0, 0, 0,
+		0, 2, 0, 0,
+		0, 0, 2, 0,
+		0, 0, 0, 1
+	]));
+}
+
+trace("/// new Matrix3D()");
+var m:Matrix3D = new Matrix3D();
+trace(m.rawData);
+trace("");
+
+trace("/// identity.appendTranslation(1, 2, 3)");
+m.appendTranslation(1, 2, 3);
+trace(m.rawData);
+trace("position:", m.position);
+trace("");
+
+trace("/// identity.prependTranslation(1, 2, 3)");
+m = new Matrix3D();
+m.prependTranslation(1, 2, 3);
+trace(m.rawData);
+trace("");
+
+trace("/// scaled.appendTranslation(1, 2, 3)");
+m = scaled();
+m.appendTranslation(1, 2, 3);
+trace(m.rawData);
+trace("position:", m.position);
+trace("");
+
+trace("/// scaled.prependTranslation(1, 2, 3)");
+m = scaled();
+m.prependTranslation(1, 2, 3);
+trace(m.rawData);
+trace("position:", m.position);
+trace("");
+
+trace("/// appendTranslation then prependTranslation");
+m = scaled();
+m.appendTranslation(10, 0, 0);
+m.prependTranslation(0, 5, 0);
+trace(m.rawData);
+trace("");
+
+trace("/// rawData is a copy");
+m = new Matrix3D();
+var raw:Vector.<Number> = m.rawData;
+raw[12] = 100;
+trace(m.rawData);
@@ -0,0 +1,60 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Matrix;
+
+function equals(lhs:Matrix, rhs:Matrix):Boolean {
+	return lhs.a == rhs.a && lhs.b == rhs.b && lhs.c == rhs.c &&
+		lhs.d == rhs.d && lhs.tx == rhs.tx && lhs.ty == rhs.ty;
+}
+
+trace("/// toString");
+trace(new Matrix());
+trace(new Matrix(0.5, -0.25, 1.5, -2, 100.125, -3));
+trace(new Matrix(NaN, Infinity, -Infinity, 0, 1e21, 1e-7));
+trace("");
+
+trace("/// clone");
+var original:Matrix = new Matrix(1, 2, 3, 4, 5, 6);
+var cloned:Matrix = original.clone();
+trace("// equals(original, cloned)");
+trace(equals(original, cloned));
+trace("// original === cloned");
+trace(original === cloned);
+trace("");
+
+trace("// cloned.translate(10, 20)");
+cloned.translate(10, 20);
+trace("// original");
+trace(original);
+trace("// cloned");
+trace(cloned);
+trace("// equals(original, cloned)");
+trace(equals(original, cloned));
+trace("");
+
+trace("// original.scale(2, 2)");
+original.scale(2, 2);
+trace("// original");
+trace(original);
+trace("// cloned");
+trace(cloned);
+trace("");
+
+trace("/// identity");
+var reset:Matrix = original;
+original.identity();
+trace("// original");
+trace(original);
+trace("// reset === original");
+trace(reset === original);
+trace("// equals(original, new Matrix())");
+trace(equals(original, new Matrix()));
+trace("// cloned");
+trace(cloned);
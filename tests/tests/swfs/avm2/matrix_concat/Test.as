@@ -0,0 +1,46 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Matrix;
+import flash.geom.Point;
+
+var point:Point = new Point(1, 0);
+var translation:Matrix = new Matrix(1, 0, 0, 1, 10, 0);
+// An exact 90 degree rotation, avoiding rounding in `Math.cos`
+var rotation:Matrix = new Matrix(0, 1, -1, 0);
+
+trace("// translation.concat(rotation)");
+var result:Matrix = translation.clone();
+result.concat(rotation);
+trace(result);
+trace("result.transformPoint(point):", result.transformPoint(point));
+trace("rotation.transformPoint(translation.transformPoint(point)):", rotation.transformPoint(translation.transformPoint(point)));
+trace("");
+
+trace("// rotation.concat(translation)");
+result = rotation.clone();
+result.concat(translation);
+trace(result);
+trace("result.transformPoint(point):", result.transformPoint(point));
+trace("translation.transformPoint(rotation.transformPoint(point)):", translation.transformPoint(rotation.transformPoint(point)));
+trace("");
+
+trace("// translation.concat(rotate(Math.PI / 2))");
+var rotate:Matrix = new Matrix();
+rotate.rotate(Math.PI / 2);
+result = translation.clone();
+result.concat(rotate);
+trace(result);
+var transformed:Point = result.transformPoint(point);
+trace("result.transformPoint(point):", Math.round(transformed.x * 1000) / 1000, Math.round(transformed.y * 1000) / 1000);
+trace("");
+
+trace("// argument is not modified");
+trace(translation);
+trace(rotation);
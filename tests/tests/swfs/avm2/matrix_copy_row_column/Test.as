@@ -0,0 +1,56 @@
+package {
+	import flash.display.MovieClip;
+	import flash.geom.Matrix;
+	import flash.geom.Matrix3D;
+	import flash.geom.Vector3D;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var m:Matrix = new Matrix();
+			var v:Vector3D = new Vector3D();
+
+			trace("/// Matrix.copyRowFrom / copyRowTo");
+			m.copyRowFrom(0, new Vector3D(2, 3, 4));
+			m.copyRowFrom(1, new Vector3D(5, 6, 7));
+			trace(m);
+			for (var row:uint = 0; row < 3; row++) {
+				m.copyRowTo(row, v);
+				trace(row + ": " + v.x + "," + v.y + "," + v.z);
+			}
+
+			trace("/// Matrix.copyColumnTo");
+			for (var col:uint = 0; col < 3; col++) {
+				m.copyColumnTo(col, v);
+				trace(col + ": " + v.x + "," + v.y + "," + v.z);
+			}
+
+			trace("/// Matrix.copyFrom");
+			var copy:Matrix = new Matrix();
+			copy.copyFrom(m);
+			trace(copy);
+
+			var m3:Matrix3D = new Matrix3D();
+			var v4:Vector3D = new Vector3D();
+
+			trace("/// Matrix3D.copyRowFrom / copyRowTo");
+			m3.copyRowFrom(1, new Vector3D(1, 2, 3, 4));
+			trace(m3.rawData);
+			m3.copyRowTo(1, v4);
+			trace(v4.x + "," + v4.y + "," + v4.z + "," + v4.w);
+
+			trace("/// Matrix3D.copyColumnFrom / copyColumnTo");
+			m3.copyColumnFrom(3, new Vector3D(10, 20, 30, 1));
+			trace(m3.rawData);
+			m3.copyColumnTo(3, v4);
+			trace(v4.x + "," + v4.y + "," + v4.z + "," + v4.w);
+			trace(m3.position);
+
+			trace("/// Matrix3D.copyFrom");
+			var copy3:Matrix3D = new Matrix3D();
+			copy3.copyFrom(m3);
+			trace(copy3.rawData);
+			m3.identity();
+			trace(copy3.rawData);
+		}
+	}
+}
@@ -0,0 +1,53 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.Matrix;
+	import flash.geom.Point;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var matrix:Matrix = new Matrix(2, 3, 4, 5, 6, 7);
+
+			trace("// matrix.createBox(2, 3)");
+			matrix.createBox(2, 3);
+			trace(matrix);
+			trace("");
+
+			trace("// matrix.createBox(2, 3, 0, 10.5, 20.25)");
+			matrix.createBox(2, 3, 0, 10.5, 20.25);
+			trace(matrix);
+			trace("");
+
+			trace("// matrix.createBox(2, 3, Math.PI / 2, 10, 20)");
+			matrix.createBox(2, 3, Math.PI / 2, 10, 20);
+			trace(matrix);
+			trace("// matrix.transformPoint(new Point(1, 0))");
+			trace(matrix.transformPoint(new Point(1, 0)));
+			trace("// matrix.transformPoint(new Point(0, 1))");
+			trace(matrix.transformPoint(new Point(0, 1)));
+			trace("");
+
+			trace("// equivalent to identity, rotate, scale, translate");
+			var manual:Matrix = new Matrix();
+			manual.rotate(Math.PI / 2);
+			manual.scale(2, 3);
+			manual.translate(10, 20);
+			trace(manual);
+			trace("");
+
+			var sprite:Sprite = new Sprite();
+
+			trace("// sprite.transform.matrix = createBox(2, 3, 0, 10.5, 20.25)");
+			matrix.createBox(2, 3, 0, 10.5, 20.25);
+			sprite.transform.matrix = matrix;
+			trace(sprite.transform.matrix);
+			trace("");
+
+			trace("// sprite.transform.matrix = createBox(2, 3, Math.PI / 2, 10, 20)");
+			matrix.createBox(2, 3, Math.PI / 2, 10, 20);
+			sprite.transform.matrix = matrix;
+			var roundTrip:Matrix = sprite.transform.matrix;
+			trace(Math.round(roundTrip.a * 1000) / 1000, Math.round(roundTrip.b * 1000) / 1000, Math.round(roundTrip.c * 1000) / 1000, Math.round(roundTrip.d * 1000) / 1000, roundTrip.tx, roundTrip.ty);
+		}
+	}
+}
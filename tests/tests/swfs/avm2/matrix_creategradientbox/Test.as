@@ -0,0 +1,36 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Matrix;
+
+var matrix:Matrix = new Matrix(2, 3, 4, 5, 6, 7);
+
+trace("// matrix.createGradientBox(1638.4, 819.2)");
+matrix.createGradientBox(1638.4, 819.2);
+trace(matrix);
+trace("");
+
+trace("// matrix.createGradientBox(100, 50, 0, 10, 20)");
+matrix.createGradientBox(100, 50, 0, 10, 20);
+trace(matrix);
+trace("");
+
+trace("// matrix.createGradientBox(1638.4, 1638.4, Math.PI / 4)");
+matrix.createGradientBox(1638.4, 1638.4, Math.PI / 4);
+trace(matrix);
+trace("");
+
+trace("// matrix.createGradientBox(3276.8, 1638.4, Math.PI / 6, -5, 5)");
+matrix.createGradientBox(3276.8, 1638.4, Math.PI / 6, -5, 5);
+trace(matrix);
+trace("");
+
+trace("// matrix.createGradientBox(-1638.4, 1638.4)");
+matrix.createGradientBox(-1638.4, 1638.4);
+trace(matrix);
@@ -0,0 +1,49 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Matrix;
+import flash.geom.Point;
+
+var point:Point = new Point(3, 5);
+
+trace("// scale + translate");
+var matrix:Matrix = new Matrix(2, 0, 0, 4, 10, 20);
+var transformed:Point = matrix.transformPoint(point);
+trace("matrix:", matrix);
+trace("matrix.transformPoint(point):", transformed);
+matrix.invert();
+trace("inverted:", matrix);
+trace("inverted.transformPoint(transformed):", matrix.transformPoint(transformed));
+trace("");
+
+trace("// general matrix");
+matrix = new Matrix(1, 2, 3, 4, 5, 6);
+transformed = matrix.transformPoint(point);
+trace("matrix:", matrix);
+trace("matrix.transformPoint(point):", transformed);
+matrix.invert();
+trace("inverted:", matrix);
+trace("inverted.transformPoint(transformed):", matrix.transformPoint(transformed));
+trace("");
+
+trace("// double inversion");
+matrix.invert();
+trace("inverted twice:", matrix);
+trace("");
+
+trace("// singular matrix");
+matrix = new Matrix(1, 2, 2, 4, 5, 6);
+matrix.invert();
+trace("inverted:", matrix);
+trace("");
+
+trace("// singular scale matrix");
+matrix = new Matrix(0, 0, 0, 1, 5, 6);
+matrix.invert();
+trace("inverted:", matrix);
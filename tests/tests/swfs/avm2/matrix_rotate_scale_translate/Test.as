@@ -0,0 +1,44 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Matrix;
+
+trace("// new Matrix() .scale(2, 3) .rotate(Math.PI / 2) .translate(5, 7)");
+var matrix:Matrix = new Matrix();
+matrix.scale(2, 3);
+trace(matrix);
+matrix.rotate(Math.PI / 2);
+trace(matrix);
+matrix.translate(5, 7);
+trace(matrix);
+trace("");
+
+trace("// new Matrix(1, 2, 3, 4, 5, 6) .scale(2, 3) .rotate(Math.PI / 2) .translate(-5, -7)");
+matrix = new Matrix(1, 2, 3, 4, 5, 6);
+matrix.scale(2, 3);
+trace(matrix);
+matrix.rotate(Math.PI / 2);
+trace(matrix);
+matrix.translate(-5, -7);
+trace(matrix);
+trace("");
+
+trace("// new Matrix() .rotate(Math.PI / 2) .scale(2, 3)");
+matrix = new Matrix();
+matrix.rotate(Math.PI / 2);
+matrix.scale(2, 3);
+trace(matrix);
+trace("");
+
+trace("// new Matrix() .rotate(Math.PI / 6) .rotate(-Math.PI / 6)");
+matrix = new Matrix();
+matrix.rotate(Math.PI / 6);
+trace(matrix);
+matrix.rotate(-Math.PI / 6);
+trace(matrix);
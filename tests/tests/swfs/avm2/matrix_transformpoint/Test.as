@@ -0,0 +1,42 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Matrix;
+import flash.geom.Point;
+
+var point:Point = new Point(3, 5);
+var matrix:Matrix = new Matrix(1, 2, 3, 4);
+
+trace("// matrix = new Matrix(1, 2, 3, 4)");
+trace("transformPoint:", matrix.transformPoint(point));
+trace("deltaTransformPoint:", matrix.deltaTransformPoint(point));
+trace("");
+
+trace("// matrix.tx = 10, matrix.ty = -20");
+matrix.tx = 10;
+matrix.ty = -20;
+trace("transformPoint:", matrix.transformPoint(point));
+trace("deltaTransformPoint:", matrix.deltaTransformPoint(point));
+trace("");
+
+trace("// identity with translation");
+matrix = new Matrix(1, 0, 0, 1, 7, 9);
+trace("transformPoint:", matrix.transformPoint(point));
+trace("deltaTransformPoint:", matrix.deltaTransformPoint(point));
+trace("");
+
+trace("// source point is not modified");
+trace(point);
+trace("");
+
+trace("// returns a new Point");
+var result:Point = matrix.transformPoint(point);
+trace(result === point);
+result = matrix.deltaTransformPoint(point);
+trace(result === point);
@@ -0,0 +1,84 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.display.Sprite;
+import flash.geom.Matrix3D;
+import flash.geom.PerspectiveProjection;
+import flash.geom.Point;
+import flash.geom.Vector3D;
+
+function round(n:Number):Number {
+	return Math.round(n * 1000) / 1000;
+}
+
+trace("/// new PerspectiveProjection()");
+var pp:PerspectiveProjection = new PerspectiveProjection();
+trace("fieldOfView:", pp.fieldOfView);
+trace("focalLength:", round(pp.focalLength));
+trace("projectionCenter:", pp.projectionCenter);
+trace("");
+
+trace("/// fieldOfView = 90");
+pp.fieldOfView = 90;
+trace("fieldOfView:", pp.fieldOfView);
+trace("focalLength:", round(pp.focalLength));
+trace("");
+
+trace("/// focalLength = 500");
+pp.focalLength = 500;
+trace("fieldOfView:", round(pp.fieldOfView));
+trace("focalLength:", round(pp.focalLength));
+trace("");
+
+var container:Sprite = new Sprite();
+
+trace("/// container.transform.perspectiveProjection (unset)");
+trace(container.transform.perspectiveProjection);
+trace("");
+
+trace("/// container.transform.perspectiveProjection = pp");
+pp.fieldOfView = 90;
+pp.projectionCenter = new Point(10, 20);
+container.transform.perspectiveProjection = pp;
+var stored:PerspectiveProjection = container.transform.perspectiveProjection;
+trace("same object:", stored === pp);
+trace("fieldOfView:", stored.fieldOfView);
+trace("focalLength:", round(stored.focalLength));
+trace("projectionCenter:", stored.projectionCenter);
+trace("");
+
+trace("/// modifying the returned projection has no effect until reassigned");
+stored.fieldOfView = 30;
+trace("fieldOfView:", container.transform.perspectiveProjection.fieldOfView);
+container.transform.perspectiveProjection = stored;
+trace("fieldOfView:", container.transform.perspectiveProjection.fieldOfView);
+trace("");
+
+trace("/// container.transform.perspectiveProjection = null");
+container.transform.perspectiveProjection = null;
+trace(container.transform.perspectiveProjection);
+trace("");
+
+trace("/// changing fieldOfView changes the projected size of a 3D child");
+var child:Sprite = new Sprite();
+var depth:Matrix3D = new Matrix3D();
+depth.appendTranslation(0, 0, 100);
+child.transform.matrix3D = depth;
+container.addChild(child);
+function projectedWidth():Number {
+	var left:Point = child.local3DToGlobal(new Vector3D(0, 0, 0));
+	var right:Point = child.local3DToGlobal(new Vector3D(100, 0, 0));
+	return round(right.x - left.x);
+}
+pp.fieldOfView = 90;
+container.transform.perspectiveProjection = pp;
+trace("fieldOfView = 90:", projectedWidth());
+pp.fieldOfView = 30;
+container.transform.perspectiveProjection = pp;
+trace("fieldOfView = 30:", projectedWidth());
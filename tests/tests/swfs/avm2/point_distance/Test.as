@@ -0,0 +1,37 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Point;
+
+trace("// Point.distance(new Point(0, 0), new Point(3, 4))");
+trace(Point.distance(new Point(0, 0), new Point(3, 4)));
+trace("");
+
+trace("// Point.distance(new Point(3, 4), new Point(0, 0))");
+trace(Point.distance(new Point(3, 4), new Point(0, 0)));
+trace("");
+
+trace("// Point.distance(new Point(-1, -2), new Point(2, 2))");
+trace(Point.distance(new Point(-1, -2), new Point(2, 2)));
+trace("");
+
+trace("// Point.distance(new Point(0, 0), new Point(Infinity, 0))");
+trace(Point.distance(new Point(0, 0), new Point(Infinity, 0)));
+trace("");
+
+trace("// Point.distance(new Point(0, 0), new Point(0, -Infinity))");
+trace(Point.distance(new Point(0, 0), new Point(0, -Infinity)));
+trace("");
+
+trace("// Point.distance(new Point(Infinity, 0), new Point(Infinity, 0))");
+trace(Point.distance(new Point(Infinity, 0), new Point(Infinity, 0)));
+trace("");
+
+trace("// Point.distance(new Point(NaN, 0), new Point(3, 4))");
+trace(Point.distance(new Point(NaN, 0), new Point(3, 4)));
@@ -0,0 +1,50 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Point;
+
+var pt1:Point = new Point(0, 0);
+var pt2:Point = new Point(10, 20);
+
+// Note that Flash weights towards `pt1` as `f` approaches 1.
+trace("// Point.interpolate(pt1, pt2, 0)");
+trace(Point.interpolate(pt1, pt2, 0));
+trace("");
+
+trace("// Point.interpolate(pt1, pt2, 1)");
+trace(Point.interpolate(pt1, pt2, 1));
+trace("");
+
+trace("// Point.interpolate(pt1, pt2, 0.5)");
+trace(Point.interpolate(pt1, pt2, 0.5));
+trace("");
+
+trace("// Point.interpolate(pt1, pt2, 0.25)");
+trace(Point.interpolate(pt1, pt2, 0.25));
+trace("");
+
+trace("// arguments are not modified");
+trace(pt1, pt2);
+trace("");
+
+trace("// Point.polar(5, 0)");
+trace(Point.polar(5, 0));
+trace("");
+
+trace("// Point.polar(5, Math.PI / 2)");
+trace(Point.polar(5, Math.PI / 2));
+trace("");
+
+trace("// Point.polar(-2, 0)");
+trace(Point.polar(-2, 0));
+trace("");
+
+trace("// Point.polar(5, Math.atan2(4, 3))");
+var polar:Point = Point.polar(5, Math.atan2(4, 3));
+trace(Math.round(polar.x * 1000) / 1000, Math.round(polar.y * 1000) / 1000);
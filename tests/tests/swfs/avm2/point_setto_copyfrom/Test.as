@@ -0,0 +1,40 @@
+package {
+	import flash.display.MovieClip;
+	import flash.geom.Point;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var p:Point = new Point(1, 2);
+			trace("/// setTo(3, -4)");
+			p.setTo(3, -4);
+			trace(p);
+			trace(p.length);
+
+			trace("/// setTo(NaN, 0.5)");
+			p.setTo(NaN, 0.5);
+			trace(p);
+
+			trace("/// copyFrom");
+			var source:Point = new Point(7, 8);
+			var target:Point = new Point();
+			target.copyFrom(source);
+			trace(target);
+			trace(target.equals(source));
+			trace(target === source);
+
+			trace("/// Modifying the source afterwards doesn't affect the copy");
+			source.setTo(100, 200);
+			trace(source);
+			trace(target);
+
+			trace("/// Modifying the copy doesn't affect the source");
+			target.offset(1, 1);
+			trace(source);
+			trace(target);
+
+			trace("/// copyFrom itself");
+			target.copyFrom(target);
+			trace(target);
+		}
+	}
+}
@@ -0,0 +1,51 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Point;
+
+trace("/// add and subtract");
+var a:Point = new Point(10, 20);
+var b:Point = new Point(3, -4);
+trace("// a.add(b)");
+trace(a.add(b));
+trace("// a.subtract(b)");
+trace(a.subtract(b));
+trace("// b.subtract(a)");
+trace(b.subtract(a));
+trace("// a, b are unchanged");
+trace(a, b);
+trace("");
+
+trace("/// normalize");
+var p:Point = new Point(3, 4);
+p.normalize(10);
+trace("// new Point(3, 4).normalize(10)");
+trace(p);
+trace("// length");
+trace(p.length);
+var zero:Point = new Point(0, 0);
+zero.normalize(10);
+trace("// new Point(0, 0).normalize(10)");
+trace(zero);
+trace("");
+
+trace("/// offset");
+var o:Point = new Point(1, 1);
+o.offset(2, -3);
+trace("// new Point(1, 1).offset(2, -3)");
+trace(o);
+trace("");
+
+trace("/// clone");
+var c:Point = a.clone();
+trace("// c.equals(a)");
+trace(c.equals(a));
+c.offset(1, 1);
+trace("// after c.offset(1, 1)");
+trace(c, a);
@@ -0,0 +1,50 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Point;
+import flash.geom.Rectangle;
+
+var rect:Rectangle = new Rectangle(10, 20, 30, 40);
+trace("// rect = " + rect);
+trace("");
+
+trace("// left edge is inclusive");
+trace("rect.contains(10, 30):", rect.contains(10, 30));
+trace("rect.containsPoint(new Point(10, 30)):", rect.containsPoint(new Point(10, 30)));
+trace("");
+
+trace("// right edge is exclusive");
+trace("rect.contains(40, 30):", rect.contains(40, 30));
+trace("rect.containsPoint(new Point(40, 30)):", rect.containsPoint(new Point(40, 30)));
+trace("rect.contains(39.9, 30):", rect.contains(39.9, 30));
+trace("");
+
+trace("// top edge is inclusive");
+trace("rect.contains(20, 20):", rect.contains(20, 20));
+trace("rect.containsPoint(new Point(20, 20)):", rect.containsPoint(new Point(20, 20)));
+trace("");
+
+trace("// bottom edge is exclusive");
+trace("rect.contains(20, 60):", rect.contains(20, 60));
+trace("rect.containsPoint(new Point(20, 60)):", rect.containsPoint(new Point(20, 60)));
+trace("rect.contains(20, 59.9):", rect.contains(20, 59.9));
+trace("");
+
+trace("// outside");
+trace("rect.contains(9.9, 30):", rect.contains(9.9, 30));
+trace("rect.contains(20, 19.9):", rect.contains(20, 19.9));
+trace("");
+
+trace("// containsRect");
+trace("rect.containsRect(rect):", rect.containsRect(rect));
+trace("rect.containsRect(new Rectangle(10, 20, 1, 1)):", rect.containsRect(new Rectangle(10, 20, 1, 1)));
+trace("rect.containsRect(new Rectangle(39, 59, 1, 1)):", rect.containsRect(new Rectangle(39, 59, 1, 1)));
+trace("rect.containsRect(new Rectangle(39, 59, 2, 1)):", rect.containsRect(new Rectangle(39, 59, 2, 1)));
+trace("rect.containsRect(new Rectangle(9, 20, 2, 2)):", rect.containsRect(new Rectangle(9, 20, 2, 2)));
+trace("rect.containsRect(new Rectangle(0, 0, 100, 100)):", rect.containsRect(new Rectangle(0, 0, 100, 100)));
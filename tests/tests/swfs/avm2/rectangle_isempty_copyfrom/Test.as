@@ -0,0 +1,66 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Rectangle;
+
+trace("/// isEmpty");
+trace("// new Rectangle(10, 10, 0, 5).isEmpty()");
+trace(new Rectangle(10, 10, 0, 5).isEmpty());
+trace("// new Rectangle(10, 10, 5, 0).isEmpty()");
+trace(new Rectangle(10, 10, 5, 0).isEmpty());
+trace("// new Rectangle(10, 10, -1, 5).isEmpty()");
+trace(new Rectangle(10, 10, -1, 5).isEmpty());
+trace("// new Rectangle(10, 10, 0.001, 0.001).isEmpty()");
+trace(new Rectangle(10, 10, 0.001, 0.001).isEmpty());
+trace("// new Rectangle(10, 10, NaN, 5).isEmpty()");
+trace(new Rectangle(10, 10, NaN, 5).isEmpty());
+trace("");
+
+trace("/// setEmpty");
+var rect:Rectangle = new Rectangle(1, 2, 3, 4);
+rect.setEmpty();
+trace("// rect");
+trace(rect);
+trace("// rect.isEmpty()");
+trace(rect.isEmpty());
+trace("");
+
+trace("/// copyFrom");
+var source:Rectangle = new Rectangle(1, 2, 3, 4);
+var copy:Rectangle = new Rectangle();
+copy.copyFrom(source);
+trace("// copy");
+trace(copy);
+trace("// copy.equals(source)");
+trace(copy.equals(source));
+trace("// copy === source");
+trace(copy === source);
+source.x = 100;
+source.width = 0;
+trace("// after changing source");
+trace("// source");
+trace(source);
+trace("// copy");
+trace(copy);
+trace("// copy.equals(source)");
+trace(copy.equals(source));
+trace("");
+
+trace("/// clone");
+var cloned:Rectangle = copy.clone();
+trace("// cloned.equals(copy)");
+trace(cloned.equals(copy));
+cloned.offset(5, 5);
+trace("// after cloned.offset(5, 5)");
+trace("// cloned");
+trace(cloned);
+trace("// copy");
+trace(copy);
+trace("// cloned.equals(copy)");
+trace(cloned.equals(copy));
@@ -0,0 +1,39 @@
+package {
+	import flash.display.MovieClip;
+	import flash.geom.Point;
+	import flash.geom.Rectangle;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var rect:Rectangle = new Rectangle(10, 20, 100, 50);
+			trace("/// size, topLeft, bottomRight");
+			trace(rect.size);
+			trace(rect.topLeft);
+			trace(rect.bottomRight);
+
+			trace("/// Mutating the returned size doesn't change the rectangle");
+			var size:Point = rect.size;
+			size.x = 1;
+			trace(rect);
+
+			trace("/// topLeft = (0, 5) keeps bottomRight in place");
+			rect.topLeft = new Point(0, 5);
+			trace(rect);
+			trace(rect.bottomRight);
+
+			trace("/// topLeft = (200, 100) past bottomRight");
+			rect.topLeft = new Point(200, 100);
+			trace(rect);
+			trace(rect.isEmpty());
+
+			trace("/// bottomRight = (50, 60) keeps topLeft in place");
+			rect = new Rectangle(10, 20, 100, 50);
+			rect.bottomRight = new Point(50, 60);
+			trace(rect);
+
+			trace("/// size = (7, 8) keeps topLeft in place");
+			rect.size = new Point(7, 8);
+			trace(rect);
+		}
+	}
+}
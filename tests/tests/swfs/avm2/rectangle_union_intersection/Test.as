@@ -0,0 +1,33 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Rectangle;
+
+function check(name:String, a:Rectangle, b:Rectangle):void {
+	trace("// " + name + ": " + a + ", " + b);
+	trace("a.union(b):", a.union(b));
+	trace("b.union(a):", b.union(a));
+	trace("a.intersection(b):", a.intersection(b));
+	trace("b.intersection(a):", b.intersection(a));
+	trace("a.intersects(b):", a.intersects(b));
+	trace("b.intersects(a):", b.intersects(a));
+	trace("");
+}
+
+var base:Rectangle = new Rectangle(0, 0, 10, 10);
+
+check("disjoint", base, new Rectangle(20, 20, 5, 5));
+check("overlapping", base, new Rectangle(5, 5, 10, 10));
+check("contained", base, new Rectangle(2, 3, 4, 5));
+check("touching edges", base, new Rectangle(10, 0, 5, 5));
+check("empty", base, new Rectangle(50, 50, 0, 0));
+check("negative size", base, new Rectangle(5, 5, -2, -2));
+
+trace("// operands are not modified");
+trace(base);
@@ -0,0 +1,30 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.ColorTransform;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			addChild(sprite);
+
+			sprite.transform.colorTransform = new ColorTransform(0.5, 0.25, 1.5, 0.75, 10, -20, 30, -40);
+			trace("/// All eight fields read back from transform.colorTransform");
+			dump(sprite.transform.colorTransform);
+
+			trace("/// Default color transform");
+			dump(new Sprite().transform.colorTransform);
+		}
+
+		private function dump(ct:ColorTransform):void {
+			trace(ct.redMultiplier);
+			trace(ct.greenMultiplier);
+			trace(ct.blueMultiplier);
+			trace(ct.alphaMultiplier);
+			trace(ct.redOffset);
+			trace(ct.greenOffset);
+			trace(ct.blueOffset);
+			trace(ct.alphaOffset);
+		}
+	}
+}
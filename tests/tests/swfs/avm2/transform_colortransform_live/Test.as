@@ -0,0 +1,32 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.ColorTransform;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			addChild(sprite);
+
+			sprite.alpha = 0.5;
+			trace("/// alpha = 0.5");
+			trace(sprite.transform.colorTransform.alphaMultiplier);
+
+			var ct:ColorTransform = sprite.transform.colorTransform;
+			ct.alphaMultiplier = 1;
+			ct.redOffset = 64;
+			trace("/// Mutating the returned ColorTransform without reassigning it");
+			trace(sprite.alpha);
+			trace(sprite.transform.colorTransform.redOffset);
+
+			sprite.transform.colorTransform = ct;
+			trace("/// After reassigning it");
+			trace(sprite.alpha);
+			trace(sprite.transform.colorTransform.alphaMultiplier);
+			trace(sprite.transform.colorTransform.redOffset);
+
+			trace("/// Two reads return distinct objects");
+			trace(sprite.transform.colorTransform == sprite.transform.colorTransform);
+		}
+	}
+}
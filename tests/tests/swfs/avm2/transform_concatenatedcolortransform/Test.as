@@ -0,0 +1,28 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.ColorTransform;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var outer:Sprite = new Sprite();
+			outer.transform.colorTransform = new ColorTransform(0.5, 1, 1, 0.5, 10, 0, 0, 0);
+			this.addChild(outer);
+
+			var inner:Sprite = new Sprite();
+			inner.transform.colorTransform = new ColorTransform(0.5, 1, 1, 0.5, 20, 0, 0, 0);
+			outer.addChild(inner);
+
+			trace("// outer.transform.concatenatedColorTransform");
+			trace(outer.transform.concatenatedColorTransform);
+			trace("// inner.transform.colorTransform");
+			trace(inner.transform.colorTransform);
+			trace("// inner.transform.concatenatedColorTransform");
+			trace(inner.transform.concatenatedColorTransform);
+
+			outer.removeChild(inner);
+			trace("// inner.transform.concatenatedColorTransform (detached)");
+			trace(inner.transform.concatenatedColorTransform);
+		}
+	}
+}
@@ -0,0 +1,42 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.Matrix;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var container:Sprite = new Sprite();
+			container.x = 10;
+			container.y = 20;
+			container.scaleX = 2;
+			container.scaleY = 3;
+			this.addChild(container);
+
+			var child:Sprite = new Sprite();
+			child.x = 5;
+			child.y = 7;
+			child.scaleX = 0.5;
+			container.addChild(child);
+
+			trace("// container.transform.concatenatedMatrix");
+			trace(container.transform.concatenatedMatrix);
+			trace("// child.transform.concatenatedMatrix");
+			trace(child.transform.concatenatedMatrix);
+
+			var manual:Matrix = child.transform.matrix.clone();
+			manual.concat(container.transform.matrix);
+			manual.concat(this.transform.matrix);
+			trace("// manual multiplication");
+			trace(manual);
+
+			var concatenated:Matrix = child.transform.concatenatedMatrix;
+			trace("// concatenatedMatrix matches manual multiplication");
+			trace(concatenated.a == manual.a && concatenated.b == manual.b && concatenated.c == manual.c && concatenated.d == manual.d && concatenated.tx == manual.tx && concatenated.ty == manual.ty);
+
+			var detached:Sprite = new Sprite();
+			detached.scaleX = 2;
+			trace("// detached.transform.concatenatedMatrix");
+			trace(detached.transform.concatenatedMatrix);
+		}
+	}
+}
@@ -0,0 +1,64 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.Matrix;
+	import flash.geom.Matrix3D;
+	import flash.geom.Vector3D;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var s:Sprite = new Sprite();
+			s.x = 10;
+			s.y = 20;
+			trace("/// 2D");
+			dump(s);
+
+			var m3:Matrix3D = new Matrix3D();
+			m3.position = new Vector3D(30, 40, 50);
+			s.transform.matrix3D = m3;
+			trace("/// matrix3D = Matrix3D at (30, 40, 50)");
+			dump(s);
+
+			m3.position = new Vector3D(0, 0, 0);
+			trace("/// mutating the assigned Matrix3D has no effect");
+			dump(s);
+
+			s.x = 5;
+			trace("/// x = 5");
+			dump(s);
+
+			s.transform.matrix3D = null;
+			trace("/// matrix3D = null");
+			dump(s);
+
+			s.transform.matrix3D = new Matrix3D();
+			s.transform.matrix = new Matrix(2, 0, 0, 2, 7, 8);
+			trace("/// matrix3D = new Matrix3D(), then matrix = (2, 0, 0, 2, 7, 8)");
+			dump(s);
+
+			try {
+				s.transform.matrix3D = new ShortMatrix3D();
+				trace("no error");
+			} catch (e) {
+				trace("caught: " + e);
+			}
+			trace("/// matrix3D = Matrix3D with only 4 elements in rawData");
+			dump(s);
+		}
+
+		private function dump(s:Sprite):void {
+			var m3:Matrix3D = s.transform.matrix3D;
+			trace("matrix: " + s.transform.matrix);
+			trace("matrix3D: " + (m3 == null ? "null" : "position " + m3.position));
+			trace("x: " + s.x + ", y: " + s.y);
+		}
+	}
+}
+
+import flash.geom.Matrix3D;
+
+class ShortMatrix3D extends Matrix3D {
+	override public function get rawData():Vector.<Number> {
+		return new Vector.<Number>(4);
+	}
+}
@@ -0,0 +1,32 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.geom.Matrix;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var shape:Shape = new Shape();
+			shape.graphics.beginFill(0xFF0000);
+			shape.graphics.drawRect(0, 0, 100, 100);
+			shape.graphics.endFill();
+			addChild(shape);
+
+			trace("/// Matrix with a = NaN");
+			shape.transform.matrix = new Matrix(NaN, 0, 0, 2, 5, NaN);
+			trace(shape.transform.matrix);
+			trace(shape.scaleX);
+			trace(shape.scaleY);
+			trace(shape.width);
+			trace(shape.height);
+			trace(shape.x);
+			trace(shape.y);
+
+			trace("/// Assigning a valid matrix afterwards");
+			shape.transform.matrix = new Matrix(1, 0, 0, 1, 10, 20);
+			trace(shape.transform.matrix);
+			trace(shape.width);
+			trace(shape.height);
+			trace(shape.getBounds(this));
+		}
+	}
+}
@@ -0,0 +1,26 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.Matrix;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			addChild(sprite);
+
+			var matrices:Array = [
+				new Matrix(),
+				new Matrix(0.5, -0.25, 1.5, 2, 10.5, -3.25),
+				new Matrix(-1, 0, 0, -1, -100, 200),
+				new Matrix(0, 1, -1, 0, 0.05, -0.05)
+			];
+			for each (var m:Matrix in matrices) {
+				sprite.transform.matrix = m;
+				var back:Matrix = sprite.transform.matrix;
+				trace("/// " + m);
+				trace(back);
+				trace(back.a == m.a && back.b == m.b && back.c == m.c && back.d == m.d && back.tx == m.tx && back.ty == m.ty);
+			}
+		}
+	}
+}
@@ -0,0 +1,22 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.Matrix;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var s:Sprite = new Sprite();
+			var values:Array = [10.037, 10.05, 10.099, -10.037, 0.06, 0.04, 123.456];
+			for each (var value:Number in values) {
+				var m:Matrix = new Matrix(1, 0, 0, 1, value, -value);
+				s.transform.matrix = m;
+				var read:Matrix = s.transform.matrix;
+				trace("// tx = " + value + ", ty = " + (-value));
+				trace("transform.matrix.tx: " + read.tx);
+				trace("transform.matrix.ty: " + read.ty);
+				trace("x: " + s.x + ", y: " + s.y);
+				trace("assigned matrix is unchanged: " + m.tx + ", " + m.ty);
+			}
+		}
+	}
+}
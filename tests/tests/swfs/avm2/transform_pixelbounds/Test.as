@@ -0,0 +1,39 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var container:Sprite = new Sprite();
+			container.x = 5;
+			container.y = 5;
+			this.addChild(container);
+
+			var child:Sprite = new Sprite();
+			child.graphics.beginFill(0xFF0000);
+			child.graphics.drawRect(0, 0, 100, 50);
+			child.graphics.endFill();
+			child.x = 10;
+			child.y = 20;
+			child.scaleX = 2;
+			child.scaleY = 0.5;
+			container.addChild(child);
+
+			trace("// container.transform.pixelBounds");
+			trace(container.transform.pixelBounds);
+			trace("// child.transform.pixelBounds");
+			trace(child.transform.pixelBounds);
+
+			container.scaleX = 2;
+			trace("// child.transform.pixelBounds (container.scaleX = 2)");
+			trace(child.transform.pixelBounds);
+
+			var empty:Sprite = new Sprite();
+			empty.x = 30;
+			empty.y = 40;
+			container.addChild(empty);
+			trace("// empty.transform.pixelBounds");
+			trace(empty.transform.pixelBounds);
+		}
+	}
+}
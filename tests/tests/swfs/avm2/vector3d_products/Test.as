@@ -0,0 +1,51 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+		}
+	}
+}
+
+import flash.geom.Vector3D;
+
+var axes:Array = [Vector3D.X_AXIS, Vector3D.Y_AXIS, Vector3D.Z_AXIS];
+var names:Array = ["X_AXIS", "Y_AXIS", "Z_AXIS"];
+
+trace("/// dotProduct");
+for (var i:int = 0; i < 3; i++) {
+	for (var j:int = 0; j < 3; j++) {
+		trace(names[i] + ".dotProduct(" + names[j] + "):", axes[i].dotProduct(axes[j]));
+	}
+}
+trace("");
+
+trace("/// crossProduct");
+for (i = 0; i < 3; i++) {
+	for (j = 0; j < 3; j++) {
+		var cross:Vector3D = axes[i].crossProduct(axes[j]);
+		trace(names[i] + ".crossProduct(" + names[j] + "):", cross, "w=" + cross.w);
+	}
+}
+trace("");
+
+trace("/// add, subtract, scaleBy");
+var a:Vector3D = new Vector3D(1, 2, 3, 4);
+var b:Vector3D = new Vector3D(4, 5, 6, 7);
+trace("a.add(b):", a.add(b), "w=" + a.add(b).w);
+trace("a.subtract(b):", a.subtract(b), "w=" + a.subtract(b).w);
+a.scaleBy(2);
+trace("a.scaleBy(2):", a, "w=" + a.w);
+trace("");
+
+trace("/// length, lengthSquared");
+var v:Vector3D = new Vector3D(3, 4, 12);
+trace("length:", v.length);
+trace("lengthSquared:", v.lengthSquared);
+trace("");
+
+trace("/// normalize");
+v = new Vector3D(3, 4, 0);
+trace("new Vector3D(3, 4, 0).normalize():", v.normalize(), v);
+v = new Vector3D(0, 0, 0);
+trace("new Vector3D(0, 0, 0).normalize():", v.normalize(), v);
@@ -14,7 +14,7 @@ use ruffle_core::external::{
     ExternalInterfaceMethod, ExternalInterfaceProvider, Value as ExternalValue, Value,
 };
 use ruffle_core::tag_utils::SwfMovie;
-use ruffle_core::{Color, Player, PlayerBuilder, PlayerEvent, ViewportDimensions};
+use ruffle_core::{Color, Locale, Player, PlayerBuilder, PlayerEvent, ViewportDimensions};
 use ruffle_web_common::JsResult;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -505,6 +505,13 @@ impl Ruffle {
             .with_ui(ui::WebUiBackend::new(js_player.clone(), &canvas))
             .with_software_video()
             .with_letterbox(config.letterbox)
+            .with_locale(
+                window
+                    .navigator()
+                    .language()
+                    .map(|tag| Locale::from_language_tag(&tag))
+                    .unwrap_or_default(),
+            )
             .with_max_execution_duration(config.max_execution_duration)
             .with_warn_on_unsupported_content(config.warn_on_unsupported_content)
             .build();